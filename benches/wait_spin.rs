@@ -0,0 +1,45 @@
+//! Compares the wakeup latency of `Getter::wait` and `Getter::wait_spin` if the setter completes quickly
+//!
+//! Run via `cargo bench --bench wait_spin`.
+
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// The amount of iterations per strategy
+const ITERATIONS: u32 = 10_000;
+
+/// Measures the average latency between `set` and the return of the wait strategy
+fn measure<F>(name: &str, wait: F)
+where
+    F: Fn(tiny_future::Getter<Instant>) -> Option<Instant>,
+{
+    // Spawn a long-lived setter thread so that thread creation does not distort the measurement
+    let (setters, setters_rx) = mpsc::channel::<tiny_future::Setter<Instant>>();
+    let worker = thread::spawn(move || {
+        for setter in setters_rx {
+            setter.set(Instant::now());
+        }
+    });
+
+    // Measure the latency
+    let mut total = Duration::ZERO;
+    for _ in 0..ITERATIONS {
+        let (setter, getter) = tiny_future::new();
+        setters.send(setter).expect("Worker thread is dead?!");
+        let set_at = wait(getter).expect("Future has been cancelled");
+        total += set_at.elapsed();
+    }
+
+    // Stop the worker and print the result
+    drop(setters);
+    worker.join().expect("Worker thread panicked");
+    println!("{name}: {:?} average latency", total / ITERATIONS);
+}
+
+fn main() {
+    measure("wait", |getter| getter.wait());
+    measure("wait_spin", |getter| getter.wait_spin(Duration::from_micros(50)));
+}
@@ -0,0 +1,47 @@
+//! Implements a type-erased getter for heterogeneous collections
+
+use crate::{future::Getter, id::FutureId};
+
+/// A type-erased getter that allows bookkeeping over futures of different value types, see [`Getter::boxed`]
+///
+/// # Note
+/// Extracting the value requires the concrete [`Getter`]; dropping an erased getter cancels the future as usual.
+pub trait AnyGetter {
+    /// The identity token of the future, see [`Getter::id`]
+    fn id(&self) -> FutureId;
+    /// Whether a result is available or not, see [`Getter::is_ready`]
+    fn is_ready(&self) -> bool;
+    /// Whether the future has been cancelled or not, see [`Getter::is_cancelled`]
+    fn is_cancelled(&self) -> bool;
+    /// Cancels the future, see [`Getter::cancel`]
+    fn cancel(&self);
+    /// Waits until the future has either completed or been cancelled without claiming the result
+    fn wait_done(&self);
+}
+impl<T> AnyGetter for Getter<T> {
+    fn id(&self) -> FutureId {
+        Getter::id(self)
+    }
+    fn is_ready(&self) -> bool {
+        Getter::is_ready(self)
+    }
+    fn is_cancelled(&self) -> bool {
+        Getter::is_cancelled(self)
+    }
+    fn cancel(&self) {
+        Getter::cancel(self)
+    }
+    fn wait_done(&self) {
+        drop(self.wait_ref());
+    }
+}
+
+impl<T> Getter<T> {
+    /// Erases the value type of the getter, see [`AnyGetter`]
+    pub fn boxed(self) -> Box<dyn AnyGetter + Send>
+    where
+        T: Send + 'static,
+    {
+        Box::new(self)
+    }
+}
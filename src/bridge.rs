@@ -0,0 +1,192 @@
+//! Implements the forwarding between futures and third-party oneshot channels
+
+use crate::future::{Getter, Setter};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc, Mutex, MutexGuard, TryLockError,
+    },
+    task::{Context, Poll, Wake, Waker},
+};
+
+/// Runs `poll` with the locked `value` until no wakeup has arrived in the meantime
+///
+/// # Note
+/// The lock is only tried, so a wakeup that arrives while `value` is being polled, e.g. because the channel wakes the
+/// waker synchronously from within `poll`, merely flags `woken` instead of deadlocking; the thread that holds the lock
+/// then polls again once it has released the lock.
+fn poll_exclusive<S, F>(value: &Mutex<S>, woken: &AtomicBool, mut poll: F)
+where
+    F: FnMut(MutexGuard<S>),
+{
+    woken.store(true, SeqCst);
+    while woken.load(SeqCst) {
+        // Leave the wakeup to the thread that holds the lock
+        let guard = match value.try_lock() {
+            Ok(guard) => guard,
+            Err(TryLockError::WouldBlock) => return,
+            Err(TryLockError::Poisoned(_)) => panic!("The bridge is poisoned?!"),
+        };
+        if woken.swap(false, SeqCst) {
+            poll(guard);
+        }
+    }
+}
+
+/// The sending half of a third-party oneshot channel
+pub(in crate) trait OneshotSender: Send {
+    /// The value type
+    type Value;
+
+    /// Polls whether the receiver has been dropped or closed, and registers the waker otherwise
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()>;
+    /// Sends the value; if the receiver is gone already, the value is simply dropped
+    fn send(self, value: Self::Value);
+}
+
+/// Forwards the result of a getter into a third-party sender
+///
+/// # Note
+/// The forward registers itself as waker of the sender, so it is woken and cancels the getter if the receiver is
+/// dropped or closed.
+struct Forward<S> {
+    /// The sender if the result has not been forwarded yet
+    sender: Mutex<Option<S>>,
+    /// Whether the forward has been woken since the sender has been polled the last time
+    woken: AtomicBool,
+    /// Cancels the getter if the receiver is gone
+    cancel: Mutex<Option<Box<dyn FnOnce() + Send>>>,
+}
+impl<S> Forward<S>
+where
+    S: OneshotSender,
+{
+    /// Sends the result if any, or closes the channel if the future has been cancelled
+    fn complete(&self, result: Option<S::Value>) {
+        // Take the sender so that a concurrent wakeup does not observe it anymore
+        let sender = self.sender.lock().expect("The bridge is poisoned?!").take();
+        if let (Some(sender), Some(value)) = (sender, result) {
+            sender.send(value);
+        }
+    }
+}
+impl<S> Wake for Forward<S>
+where
+    S: OneshotSender + 'static,
+{
+    fn wake(self: Arc<Self>) {
+        let waker = Waker::from(self.clone());
+        poll_exclusive(&self.sender, &self.woken, |mut guard| {
+            // Re-register the waker and check whether the receiver is gone
+            let Some(sender) = guard.as_mut() else {
+                return;
+            };
+            if sender.poll_closed(&mut Context::from_waker(&waker)).is_pending() {
+                return;
+            }
+
+            // Cancel the getter; this must happen outside of the lock since the cancellation completes the forward
+            drop(guard);
+            let cancel = self.cancel.lock().expect("The bridge is poisoned?!").take();
+            if let Some(cancel) = cancel {
+                cancel();
+            }
+        });
+    }
+}
+
+/// Drives a third-party receiver and completes a setter with the received value
+///
+/// # Note
+/// The bridge registers itself as waker of the receiver, so it is polled again on the thread that sends the value or
+/// drops the sender.
+struct Bridge<R, T> {
+    /// The receiver and the setter if the value has not been received yet
+    pending: Mutex<Option<(R, Setter<T>)>>,
+    /// Whether the bridge has been woken since the receiver has been polled the last time
+    woken: AtomicBool,
+}
+impl<R, T> Bridge<R, T> {
+    /// Closes the channel since the getter has lost interest
+    fn close(&self) {
+        // Drop the receiver and the setter outside of the lock
+        let pending = self.pending.lock().expect("The bridge is poisoned?!").take();
+        drop(pending);
+    }
+}
+impl<R, T, E> Wake for Bridge<R, T>
+where
+    R: Future<Output = Result<T, E>> + Unpin + Send + 'static,
+    T: Send + 'static,
+{
+    fn wake(self: Arc<Self>) {
+        let waker = Waker::from(self.clone());
+        poll_exclusive(&self.pending, &self.woken, |mut pending| {
+            // Poll the receiver and take it together with the setter if it is resolved
+            let Some((receiver, _)) = pending.as_mut() else {
+                return;
+            };
+            let Poll::Ready(result) = Pin::new(receiver).poll(&mut Context::from_waker(&waker)) else {
+                return;
+            };
+            let (_, setter) = pending.take().expect("The bridge is empty?!");
+            drop(pending);
+
+            // Complete the future outside of the lock, or cancel it if the sender has been dropped without a value
+            match result {
+                Ok(value) => setter.set(value),
+                Err(_) => drop(setter),
+            }
+        });
+    }
+}
+
+/// Forwards the result of `getter` into `sender`, and cancels `getter` if the receiver is dropped or closed
+///
+/// # Note
+/// No thread or runtime is involved: the result is sent on the thread that completes the future, and the sender is
+/// dropped if the future is cancelled.
+pub(in crate) fn forward<S>(getter: Getter<S::Value>, sender: S)
+where
+    S: OneshotSender + 'static,
+    S::Value: Send + 'static,
+{
+    let cancel: Box<dyn FnOnce() + Send> = Box::new(getter.canceller());
+    let forward = Arc::new(Forward {
+        sender: Mutex::new(Some(sender)),
+        woken: AtomicBool::new(false),
+        cancel: Mutex::new(Some(cancel)),
+    });
+
+    // Watch the receiver and forward the result
+    forward.clone().wake();
+    getter.on_complete(move |result| forward.complete(result));
+}
+
+/// Creates a getter that resolves to the value received from `receiver`
+///
+/// # Note
+/// No thread or runtime is involved: the future is completed on the thread that sends the value, and cancelled if the
+/// receiver fails. If the getter is dropped or cancelled, the receiver is dropped.
+pub(in crate) fn receive<R, T, E>(receiver: R) -> Getter<T>
+where
+    R: Future<Output = Result<T, E>> + Unpin + Send + 'static,
+    T: Send + 'static,
+{
+    // Close the channel if the getter loses interest
+    let (setter, getter) = crate::new();
+    let bridge = Arc::new(Bridge { pending: Mutex::new(None), woken: AtomicBool::new(false) });
+    let weak = Arc::downgrade(&bridge);
+    setter.on_cancelled(move || {
+        if let Some(bridge) = weak.upgrade() {
+            bridge.close();
+        }
+    });
+
+    // Poll the receiver once to register the bridge as waker
+    *bridge.pending.lock().expect("The bridge is poisoned?!") = Some((receiver, setter));
+    bridge.wake();
+    getter
+}
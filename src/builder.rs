@@ -0,0 +1,75 @@
+//! Implements a builder for futures with per-future configuration
+
+use crate::future::{Getter, Setter};
+use std::time::Instant;
+
+/// A reusable builder for similarly configured futures
+///
+/// # Note
+/// Options that are not configured behave exactly like [`crate::new`].
+#[derive(Debug, Default, Clone)]
+pub struct FutureBuilder {
+    /// The label of the futures if any
+    name: Option<String>,
+    /// The deadline to announce if any
+    deadline: Option<Instant>,
+    /// Whether dropping an unconsumed getter is reported or not
+    must_consume: bool,
+}
+impl FutureBuilder {
+    /// Creates a new builder without any options configured
+    pub const fn new() -> Self {
+        Self { name: None, deadline: None, must_consume: false }
+    }
+
+    /// Sets the human-readable label of the futures, see [`Getter::label`]
+    pub fn name<S>(mut self, name: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.name = Some(name.into());
+        self
+    }
+    /// Announces a deadline for the futures, see [`Setter::set_deadline`]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+    /// Reports getters that are dropped without ever having been waited on, polled via [`Getter::try_get`], or detached
+    ///
+    /// # Note
+    /// Unconsumed getters are reported to the hook installed via [`crate::set_unconsumed_hook`]; without a hook, they
+    /// panic in debug builds and are ignored in release builds.
+    pub fn must_consume(mut self) -> Self {
+        self.must_consume = true;
+        self
+    }
+
+    /// Creates a new future with the configured options
+    ///
+    /// # Note
+    /// Like [`crate::new`], the future is cancelled if the last setter is dropped without setting a result.
+    pub fn build<T>(&self) -> (Setter<T>, Getter<T>) {
+        let (setter, getter) = crate::new();
+        if let Some(name) = &self.name {
+            getter.set_label(name.clone());
+        }
+        if let Some(deadline) = self.deadline {
+            setter.set_deadline(deadline);
+        }
+        if self.must_consume {
+            getter.set_must_consume();
+        }
+        (setter, getter)
+    }
+    /// Creates a new future with the configured options that is completed with the default value instead of being
+    /// cancelled if the last setter is dropped without setting a result, see [`Setter::on_drop_complete_with_default`]
+    pub fn build_with_default<T>(&self) -> (Setter<T>, Getter<T>)
+    where
+        T: Default,
+    {
+        let (setter, getter) = self.build();
+        setter.on_drop_complete_with_default();
+        (setter, getter)
+    }
+}
@@ -0,0 +1,140 @@
+//! Implements futures that stream partial results before the final value
+
+use crate::future::{Getter, Setter};
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug, Formatter},
+    mem,
+    sync::{Arc, Condvar, Mutex},
+};
+
+/// The queue of partial results
+struct Queue<C> {
+    /// The partial results that have not been taken yet
+    chunks: VecDeque<C>,
+    /// Whether no more partial results will be pushed
+    closed: bool,
+}
+
+/// The shared queue of partial results
+struct Chunks<C> {
+    /// The queue
+    queue: Mutex<Queue<C>>,
+    /// The signal variable
+    signal: Condvar,
+}
+impl<C> Chunks<C> {
+    /// Closes the queue and wakes the getter, and drops the queued partial results if `discard` is set
+    fn close(&self, discard: bool) {
+        let mut queue = self.queue.lock().expect("The queue is poisoned?!");
+        queue.closed = true;
+        let chunks = if discard { mem::take(&mut queue.chunks) } else { VecDeque::new() };
+        self.signal.notify_all();
+
+        // Drop the partial results outside of the lock
+        drop(queue);
+        drop(chunks);
+    }
+}
+
+/// The producing half of a chunked future, see [`chunked`]
+pub struct ChunkSetter<T, C> {
+    /// The setter for the final value, or `None` if the final value has been set
+    setter: Option<Setter<T>>,
+    /// The shared queue of partial results
+    chunks: Arc<Chunks<C>>,
+}
+impl<T, C> ChunkSetter<T, C> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.setter.as_ref().is_none_or(Setter::is_cancelled)
+    }
+
+    /// Queues a partial result, returns the chunk back if the future has been cancelled
+    pub fn push(&self, chunk: C) -> Result<(), C> {
+        let mut queue = self.chunks.queue.lock().expect("The queue is poisoned?!");
+        if queue.closed {
+            return Err(chunk);
+        }
+
+        // Queue the chunk and wake the getter
+        queue.chunks.push_back(chunk);
+        self.chunks.signal.notify_all();
+        Ok(())
+    }
+    /// Sets the final value; partial results that have been pushed before can still be drained by the getter
+    pub fn set(mut self, value: T) {
+        if let Some(setter) = self.setter.take() {
+            setter.set(value);
+        }
+    }
+}
+impl<T, C> Debug for ChunkSetter<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkSetter").field("setter", &self.setter).field("chunks", &"<opaque>").finish()
+    }
+}
+impl<T, C> Drop for ChunkSetter<T, C> {
+    fn drop(&mut self) {
+        // Close the queue; if the final value has not been set, the future is cancelled and the chunks are dropped
+        let setter = self.setter.take();
+        let cancelled = setter.is_some();
+        drop(setter);
+        self.chunks.close(cancelled);
+    }
+}
+
+/// The consuming half of a chunked future, see [`chunked`]
+pub struct ChunkGetter<T, C> {
+    /// The getter for the final value
+    getter: Getter<T>,
+    /// The shared queue of partial results
+    chunks: Arc<Chunks<C>>,
+}
+impl<T, C> ChunkGetter<T, C> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.getter.is_cancelled()
+    }
+
+    /// Blocks until the next partial result is available and takes it, returns `None` once all partial results have
+    /// been drained and the final value has been set, or if the future has been cancelled
+    pub fn next_chunk(&self) -> Option<C> {
+        let queue = self.chunks.queue.lock().expect("The queue is poisoned?!");
+        let mut queue = (self.chunks.signal)
+            .wait_while(queue, |queue| queue.chunks.is_empty() && !queue.closed)
+            .expect("The queue is poisoned?!");
+        queue.chunks.pop_front()
+    }
+    /// Waits until the final value is set, returns either `Some(value)` or `None` if the future has been cancelled
+    ///
+    /// # Note
+    /// Partial results that have not been drained yet are dropped.
+    pub fn finish(self) -> Option<T> {
+        self.getter.wait()
+    }
+}
+impl<T, C> Debug for ChunkGetter<T, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkGetter").field("getter", &self.getter).field("chunks", &"<opaque>").finish()
+    }
+}
+
+/// Creates a new future that streams partial results of type `C` before the final value of type `T`
+///
+/// # Note
+/// The partial results are queued without bound, so a slow getter does not block the setter. If the future is cancelled
+/// from either side, the queued partial results are dropped immediately and further pushes are rejected.
+pub fn chunked<T, C>() -> (ChunkSetter<T, C>, ChunkGetter<T, C>)
+where
+    C: Send + 'static,
+{
+    let (setter, getter) = crate::new();
+    let queue = Queue { chunks: VecDeque::new(), closed: false };
+    let chunks = Arc::new(Chunks { queue: Mutex::new(queue), signal: Condvar::new() });
+
+    // Drop the queued partial results if the getter cancels the future
+    let chunks_ = chunks.clone();
+    setter.on_cancelled(move || chunks_.close(true));
+    (ChunkSetter { setter: Some(setter), chunks: chunks.clone() }, ChunkGetter { getter, chunks })
+}
@@ -0,0 +1,688 @@
+//! Implements combinators to chain and compose futures
+
+use crate::{
+    future::{Getter, Setter},
+    outcome::Either,
+    timer::TimerEntry,
+};
+use std::{
+    any::Any,
+    collections::VecDeque,
+    fmt::{self, Debug, Formatter},
+    mem,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+/// The shared state of two joined futures
+struct Join2<A, B> {
+    /// The result of the first future
+    a: Option<A>,
+    /// The result of the second future
+    b: Option<B>,
+    /// The setter for the joined future
+    setter: Option<Setter<(A, B)>>,
+}
+impl<A, B> Join2<A, B> {
+    /// Completes the joined future if both results are available
+    fn complete(state: &Mutex<Self>) {
+        // Take the results and the setter if both results are available
+        let mut state = state.lock().expect("The future is poisoned?!");
+        let (Some(_), Some(_)) = (&state.a, &state.b) else {
+            return;
+        };
+        let (setter, a, b) = (state.setter.take(), state.a.take(), state.b.take());
+        drop(state);
+
+        // Complete the joined future outside of the lock
+        if let (Some(setter), Some(a), Some(b)) = (setter, a, b) {
+            setter.set((a, b));
+        }
+    }
+    /// Cancels the joined future
+    fn cancel(state: &Mutex<Self>) {
+        // Take the setter and drop it outside of the lock
+        let setter = state.lock().expect("The future is poisoned?!").setter.take();
+        drop(setter);
+    }
+}
+
+/// The shared state of two racing futures
+struct Race<T> {
+    /// The setter for the raced future
+    setter: Option<Setter<T>>,
+    /// The amount of cancelled inputs
+    cancelled: usize,
+}
+impl<T> Race<T> {
+    /// Completes the raced future with `value` if no other input has won yet, returns whether `value` has won
+    fn complete(state: &Mutex<Self>, value: T) -> bool {
+        // Take the setter and complete the raced future outside of the lock
+        let setter = state.lock().expect("The future is poisoned?!").setter.take();
+        match setter {
+            Some(setter) => {
+                setter.set(value);
+                true
+            }
+            None => false,
+        }
+    }
+    /// Records a cancelled input and cancels the raced future if both inputs have been cancelled
+    fn cancel(state: &Mutex<Self>) {
+        // Take the setter if both inputs have been cancelled
+        let mut state = state.lock().expect("The future is poisoned?!");
+        state.cancelled += 1;
+        let setter = match state.cancelled {
+            2 => state.setter.take(),
+            _ => None,
+        };
+
+        // Drop the setter outside of the lock
+        drop(state);
+        drop(setter);
+    }
+}
+
+/// A type-erased handle that cancels an input future
+type Canceller = Box<dyn FnOnce() + Send>;
+
+/// The shared state of a fold over several futures
+struct FoldState<T, A, F> {
+    /// The accumulator, or `None` while a result is being folded
+    acc: Option<A>,
+    /// The folding function, or `None` while a result is being folded
+    f: Option<F>,
+    /// The results that have not been folded yet
+    queue: VecDeque<T>,
+    /// The amount of unresolved inputs
+    remaining: usize,
+    /// Whether a cancelled input fails the whole fold or not
+    strict: bool,
+    /// The setter for the folded future
+    setter: Option<Setter<A>>,
+    /// The cancellers for the inputs to cancel them if the fold fails
+    cancellers: Vec<Canceller>,
+}
+impl<T, A, F> FoldState<T, A, F> {
+    /// Folds the result of a resolved input into the accumulator and completes the folded future if all inputs are
+    /// resolved
+    ///
+    /// # Note
+    /// The folding function runs outside of the lock; results that arrive in the meantime are queued and folded by the
+    /// thread that is folding already. If the folding function panics, the fold fails and the panic payload is delivered
+    /// to the folded future as if [`Setter::complete_with`] was used, so the thread that resolved the input does not
+    /// unwind.
+    fn complete(state: &Mutex<Self>, result: Option<T>)
+    where
+        F: FnMut(A, T) -> A,
+    {
+        // Do nothing if the fold has failed already
+        let mut guard = state.lock().expect("The future is poisoned?!");
+        if guard.setter.is_none() {
+            return;
+        }
+
+        // Queue the result or fail the fold if the input has been cancelled in strict mode
+        match result {
+            Some(value) => guard.queue.push_back(value),
+            None if !guard.strict => (),
+            None => return Self::fail(guard, None),
+        }
+        guard.remaining -= 1;
+
+        // Fold the queued results outside of the lock unless another thread is folding already
+        while guard.f.is_some() && guard.setter.is_some() {
+            let Some(value) = guard.queue.pop_front() else {
+                break;
+            };
+            let mut f = guard.f.take().expect("The fold has no folding function?!");
+            let acc = guard.acc.take().expect("The fold has no accumulator?!");
+            drop(guard);
+            let folded = panic::catch_unwind(AssertUnwindSafe(|| f(acc, value)));
+
+            // Store the accumulator back, or fail the fold and deliver the panic to the folded future
+            guard = state.lock().expect("The future is poisoned?!");
+            match folded {
+                Ok(acc) => (guard.f, guard.acc) = (Some(f), Some(acc)),
+                Err(payload) => return Self::fail(guard, Some(payload)),
+            }
+        }
+
+        // Complete the folded future if all inputs are resolved and folded
+        if guard.remaining == 0 && guard.f.is_some() && guard.queue.is_empty() {
+            let (setter, acc) = (guard.setter.take(), guard.acc.take());
+            drop(guard);
+            if let (Some(setter), Some(acc)) = (setter, acc) {
+                setter.set(acc);
+            }
+        }
+    }
+    /// Fails the fold by cancelling the folded future and all remaining inputs, and delivers the panic payload of the
+    /// folding function to the folded future if any
+    fn fail(mut state: MutexGuard<Self>, panic: Option<Box<dyn Any + Send>>) {
+        // Take the setter and the cancellers and invoke them outside of the lock
+        let (setter, cancellers) = (state.setter.take(), mem::take(&mut state.cancellers));
+        drop(state);
+        match (setter, panic) {
+            (Some(setter), Some(payload)) => setter.complete_with(|| panic::resume_unwind(payload)),
+            (setter, _) => drop(setter),
+        }
+        for canceller in cancellers {
+            canceller();
+        }
+    }
+}
+
+/// The shared state of a quorum over several futures
+struct Quorum<T> {
+    /// The successful results in completion order
+    values: Vec<T>,
+    /// The amount of successful results that are needed
+    needed: usize,
+    /// The amount of unresolved inputs
+    pending: usize,
+    /// The setter for the quorum future
+    setter: Option<Setter<Vec<T>>>,
+    /// The cancellers for the inputs to cancel the stragglers
+    cancellers: Vec<Canceller>,
+}
+impl<T> Quorum<T> {
+    /// Records the result of a resolved input and completes or cancels the quorum future once the outcome is known
+    fn complete(state: &Mutex<Self>, result: Option<T>) {
+        // Do nothing if the quorum has been decided already
+        let mut state = state.lock().expect("The future is poisoned?!");
+        if state.setter.is_none() {
+            return;
+        }
+
+        // Record the result
+        state.pending -= 1;
+        if let Some(value) = result {
+            state.values.push(value);
+        }
+
+        // Decide the quorum if it has been met or has become impossible
+        let (met, possible) = (state.values.len() == state.needed, state.values.len() + state.pending >= state.needed);
+        if met || !possible {
+            // Take the setter and the cancellers and invoke them outside of the lock
+            let (setter, cancellers, values) =
+                (state.setter.take(), mem::take(&mut state.cancellers), mem::take(&mut state.values));
+            drop(state);
+            if let (Some(setter), true) = (setter, met) {
+                setter.set(values);
+            }
+            for canceller in cancellers {
+                canceller();
+            }
+        }
+    }
+}
+
+/// The shared state of several joined futures that are collected in their original order
+struct JoinAll<T, R> {
+    /// The results in the original order
+    values: Vec<Option<T>>,
+    /// The amount of unresolved inputs
+    pending: usize,
+    /// Converts the collected results into the joined result
+    finish: fn(Vec<T>) -> R,
+    /// The setter for the joined future
+    setter: Option<Setter<R>>,
+    /// The cancellers for the inputs to cancel them if the join fails
+    cancellers: Vec<Canceller>,
+}
+impl<T, R> JoinAll<T, R> {
+    /// Joins `getters` into one future, where `short` either accepts a result or short-circuits the join with a final
+    /// result
+    fn start<I>(getters: I, short: fn(T) -> Result<T, R>, finish: fn(Vec<T>) -> R) -> Getter<R>
+    where
+        I: IntoIterator<Item = Getter<T>>,
+        T: Send + 'static,
+        R: Send + 'static,
+    {
+        // Complete trivial joins immediately
+        let getters: Vec<_> = getters.into_iter().collect();
+        if getters.is_empty() {
+            return crate::ready(finish(Vec::new()));
+        }
+
+        // Propagate cancellation back to all inputs
+        let (setter, getter) = crate::new();
+        for input in getters.iter() {
+            setter.on_cancelled(input.canceller());
+        }
+
+        // Create the shared state before any callback can fire
+        let cancellers = getters.iter().map(|input| Box::new(input.canceller()) as Canceller).collect();
+        let values = getters.iter().map(|_| None).collect();
+        let state = JoinAll { values, pending: getters.len(), finish, setter: Some(setter), cancellers };
+
+        // Register the completion callbacks
+        let state = Arc::new(Mutex::new(state));
+        for (index, input) in getters.into_iter().enumerate() {
+            let state = state.clone();
+            input.on_complete(move |result| Self::complete(&state, index, result.map(short)));
+        }
+        getter
+    }
+
+    /// Records the result of a resolved input and completes or fails the joined future once the outcome is known
+    fn complete(state: &Mutex<Self>, index: usize, result: Option<Result<T, R>>) {
+        // Do nothing if the join has been decided already
+        let mut state = state.lock().expect("The future is poisoned?!");
+        if state.setter.is_none() {
+            return;
+        }
+
+        // Record the result, or take the setter and the cancellers to fail the join outside of the lock
+        let early = match result {
+            Some(Ok(value)) => {
+                state.values[index] = Some(value);
+                state.pending -= 1;
+                None
+            }
+            Some(Err(result)) => Some(Some(result)),
+            None => Some(None),
+        };
+        if let Some(result) = early {
+            let (setter, cancellers) = (state.setter.take(), mem::take(&mut state.cancellers));
+            drop(state);
+            if let (Some(setter), Some(result)) = (setter, result) {
+                setter.set(result);
+            }
+            for canceller in cancellers {
+                canceller();
+            }
+            return;
+        }
+
+        // Complete the joined future if all inputs are resolved
+        if state.pending == 0 {
+            let (setter, values) = (state.setter.take(), mem::take(&mut state.values));
+            let result = (state.finish)(values.into_iter().flatten().collect());
+            drop(state);
+            if let Some(setter) = setter {
+                setter.set(result);
+            }
+        }
+    }
+}
+
+/// A fold over several futures that consumes their results in completion order, see [`fold`]
+pub struct Fold<T, A, F> {
+    /// The input futures
+    getters: Vec<Getter<T>>,
+    /// The initial accumulator
+    init: A,
+    /// The folding function
+    f: F,
+    /// Whether a cancelled input fails the whole fold or not
+    strict: bool,
+}
+impl<T, A, F> Fold<T, A, F>
+where
+    T: Send + 'static,
+    A: Send + 'static,
+    F: FnMut(A, T) -> A + Send + 'static,
+{
+    /// Fails the whole fold if any input is cancelled, instead of skipping cancelled inputs
+    ///
+    /// # Note
+    /// If the fold fails, the folded future is cancelled together with all remaining inputs.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Starts the fold, returns a getter that completes with the accumulator once all inputs are resolved
+    ///
+    /// # Note
+    /// The results are folded on the threads that complete the respective inputs; no helper thread is spawned. Dropping
+    /// or cancelling the returned getter cancels all inputs.
+    pub fn start(self) -> Getter<A> {
+        // Complete immediately if there are no inputs
+        let (setter, getter) = crate::new();
+        if self.getters.is_empty() {
+            setter.set(self.init);
+            return getter;
+        }
+
+        // Propagate cancellation back to all inputs
+        for input in self.getters.iter() {
+            setter.on_cancelled(input.canceller());
+        }
+
+        // Create the shared state before any callback can fire
+        let cancellers = self.getters.iter().map(|input| Box::new(input.canceller()) as Canceller).collect();
+        let state = FoldState {
+            acc: Some(self.init),
+            f: Some(self.f),
+            queue: VecDeque::new(),
+            remaining: self.getters.len(),
+            strict: self.strict,
+            setter: Some(setter),
+            cancellers,
+        };
+
+        // Register the completion callbacks
+        let state = Arc::new(Mutex::new(state));
+        for input in self.getters {
+            let state = state.clone();
+            input.on_complete(move |result| FoldState::complete(&state, result));
+        }
+        getter
+    }
+}
+impl<T, A, F> Debug for Fold<T, A, F>
+where
+    A: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fold")
+            .field("getters", &self.getters)
+            .field("init", &self.init)
+            .field("f", &"<opaque>")
+            .field("strict", &self.strict)
+            .finish()
+    }
+}
+
+/// Races two futures and maps the winning result into a common type
+fn race_map<A, B, T>(a: Getter<A>, b: Getter<B>, map_a: fn(A) -> T, map_b: fn(B) -> T) -> Getter<T>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    T: Send + 'static,
+{
+    // Propagate cancellation back to both inputs
+    let (setter, getter) = crate::new();
+    setter.on_cancelled(a.canceller());
+    setter.on_cancelled(b.canceller());
+
+    // Create the shared state and the cancellers to cancel the respective loser
+    let (cancel_a, cancel_b) = (a.canceller(), b.canceller());
+    let state = Arc::new(Mutex::new(Race { setter: Some(setter), cancelled: 0 }));
+
+    // Register the completion callbacks
+    let state_ = state.clone();
+    a.on_complete(move |result| match result.map(|value| Race::complete(&state_, map_a(value))) {
+        Some(true) => cancel_b(),
+        Some(false) => (),
+        None => Race::cancel(&state_),
+    });
+    b.on_complete(move |result| match result.map(|value| Race::complete(&state, map_b(value))) {
+        Some(true) => cancel_a(),
+        Some(false) => (),
+        None => Race::cancel(&state),
+    });
+    getter
+}
+
+/// Races two futures and completes with the result of whichever finishes first
+///
+/// # Note
+/// The losing input is cancelled as soon as the winner completes; if both inputs are cancelled, the raced future is
+/// cancelled too. Dropping or cancelling the raced getter cancels both inputs.
+pub fn race<T>(a: Getter<T>, b: Getter<T>) -> Getter<T>
+where
+    T: Send + 'static,
+{
+    race_map(a, b, |value| value, |value| value)
+}
+/// Races two futures of different types and completes with the result of whichever finishes first, see [`race`]
+pub fn race_either<A, B>(a: Getter<A>, b: Getter<B>) -> Getter<Either<A, B>>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+{
+    race_map(a, b, Either::Left, Either::Right)
+}
+
+/// Folds the results of several futures in completion order into an accumulator, see [`Fold::start`]
+///
+/// # Note
+/// By default, cancelled inputs are skipped; use [`Fold::strict`] to fail the whole fold instead.
+pub fn fold<T, A, F>(getters: Vec<Getter<T>>, init: A, f: F) -> Fold<T, A, F>
+where
+    T: Send + 'static,
+    A: Send + 'static,
+    F: FnMut(A, T) -> A + Send + 'static,
+{
+    Fold { getters, init, f, strict: false }
+}
+
+/// Completes with the first `n` successful results of `getters` in completion order
+///
+/// # Note
+/// Once the quorum is met, the remaining inputs are cancelled. If so many inputs are cancelled that the quorum cannot be
+/// met anymore, the quorum future is cancelled together with the remaining inputs. If `n` is zero, the quorum future
+/// completes immediately with an empty vector; if `n` exceeds the amount of inputs, it is cancelled immediately. In both
+/// cases, all inputs are cancelled. Dropping or cancelling the quorum getter cancels all inputs.
+pub fn quorum<T>(getters: Vec<Getter<T>>, n: usize) -> Getter<Vec<T>>
+where
+    T: Send + 'static,
+{
+    // Decide trivial quorums immediately
+    if n == 0 {
+        return crate::ready(Vec::new());
+    }
+    if n > getters.len() {
+        return crate::cancelled();
+    }
+
+    // Propagate cancellation back to all inputs
+    let (setter, getter) = crate::new();
+    for input in getters.iter() {
+        setter.on_cancelled(input.canceller());
+    }
+
+    // Create the shared state before any callback can fire
+    let cancellers = getters.iter().map(|input| Box::new(input.canceller()) as Canceller).collect();
+    let state =
+        Quorum { values: Vec::with_capacity(n), needed: n, pending: getters.len(), setter: Some(setter), cancellers };
+
+    // Register the completion callbacks
+    let state = Arc::new(Mutex::new(state));
+    for input in getters {
+        let state = state.clone();
+        input.on_complete(move |result| Quorum::complete(&state, result));
+    }
+    getter
+}
+
+/// Joins two futures into one that completes with both results, see [`Getter::zip`]
+pub fn join2<A, B>(a: Getter<A>, b: Getter<B>) -> Getter<(A, B)>
+where
+    A: Send + 'static,
+    B: Send + 'static,
+{
+    a.zip(b)
+}
+
+impl<T> Getter<T> {
+    /// Chains a dependent asynchronous step that is started with the result of this future
+    ///
+    /// # Note
+    /// The returned getter completes with the result of the getter returned by `f`, and is cancelled if either stage is
+    /// cancelled. Cancelling the returned getter cancels whichever stage is currently in flight.
+    pub fn and_then<U, F>(self, f: F) -> Getter<U>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: FnOnce(T) -> Getter<U> + Send + 'static,
+    {
+        // Propagate cancellation back to the first stage while it is in flight
+        let (setter, getter) = crate::new();
+        setter.on_cancelled(self.canceller());
+
+        // Start the second stage once the first stage has completed
+        self.on_complete(move |result| match result {
+            Some(value) => setter.forward(f(value)),
+            None => drop(setter),
+        });
+        getter
+    }
+    /// Joins this future with `other` into one that completes with both results
+    ///
+    /// # Note
+    /// The joined future is cancelled if either input is cancelled, in which case the other input is cancelled too.
+    /// Dropping or cancelling the joined getter cancels both inputs. No thread is blocked while waiting for the inputs.
+    pub fn zip<U>(self, other: Getter<U>) -> Getter<(T, U)>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+    {
+        // Propagate cancellation back to both inputs
+        let (setter, getter) = crate::new();
+        setter.on_cancelled(self.canceller());
+        setter.on_cancelled(other.canceller());
+
+        // Create the shared state and the cancellers to cancel the respective other input
+        let (cancel_self, cancel_other) = (self.canceller(), other.canceller());
+        let state = Arc::new(Mutex::new(Join2 { a: None, b: None, setter: Some(setter) }));
+
+        // Register the completion callbacks
+        let state_ = state.clone();
+        self.on_complete(move |result| match result {
+            Some(value) => {
+                state_.lock().expect("The future is poisoned?!").a = Some(value);
+                Join2::complete(&state_);
+            }
+            None => {
+                Join2::cancel(&state_);
+                cancel_other();
+            }
+        });
+        other.on_complete(move |result| match result {
+            Some(value) => {
+                state.lock().expect("The future is poisoned?!").b = Some(value);
+                Join2::complete(&state);
+            }
+            None => {
+                Join2::cancel(&state);
+                cancel_self();
+            }
+        });
+        getter
+    }
+    /// Falls back to `other` if this future is cancelled
+    ///
+    /// # Note
+    /// The returned getter completes with the result of this future if it completes successfully, in which case `other`
+    /// is cancelled; otherwise it completes with the result of `other`. Dropping or cancelling the returned getter
+    /// cancels both sources.
+    pub fn or(self, other: Getter<T>) -> Getter<T>
+    where
+        T: Send + 'static,
+    {
+        // Propagate cancellation back to both sources
+        let (setter, getter) = crate::new();
+        setter.on_cancelled(self.canceller());
+        setter.on_cancelled(other.canceller());
+
+        // Consult the fallback only if this future is cancelled; dropping the unused fallback cancels it
+        self.on_complete(move |result| match result {
+            Some(value) => {
+                drop(other);
+                setter.set(value);
+            }
+            None => setter.forward(other),
+        });
+        getter
+    }
+}
+impl<T> Getter<T>
+where
+    T: Send + 'static,
+{
+    /// Wraps the getter into one that is cancelled automatically if the result has not arrived within `timeout`
+    ///
+    /// # Note
+    /// The timeout is scheduled on a shared timer thread; the timer is disarmed if the result arrives early or if the
+    /// returned getter is dropped or cancelled. On expiry, this future is cancelled as well.
+    pub fn with_timeout(self, timeout: Duration) -> Getter<T> {
+        // Propagate cancellation back to this future and store the setter so that the timer can take it
+        let (setter, getter) = crate::new();
+        setter.on_cancelled(self.canceller());
+        let setter = Arc::new(Mutex::new(Some(setter)));
+
+        // Schedule the expiry which cancels both futures; this future is cancelled first so that the cancellation is
+        // visible once the returned getter observes the expiry
+        let (setter_, cancel_self) = (setter.clone(), self.canceller());
+        let timer = TimerEntry::after(timeout, move || {
+            cancel_self();
+            let setter = setter_.lock().expect("The future is poisoned?!").take();
+            drop(setter);
+        });
+
+        // Disarm the timer if the returned getter is cancelled
+        if let Some(setter) = setter.lock().expect("The future is poisoned?!").as_ref() {
+            setter.on_cancelled(move || {
+                timer.disarm();
+            });
+        }
+
+        // Disarm the timer and forward the result if it arrives in time
+        self.on_complete(move |result| {
+            timer.disarm();
+            let setter = setter.lock().expect("The future is poisoned?!").take();
+            if let (Some(setter), Some(value)) = (setter, result) {
+                setter.set(value);
+            }
+        });
+        getter
+    }
+}
+
+impl<T> Getter<Getter<T>> {
+    /// Flattens a nested future into one that completes with the inner result
+    ///
+    /// # Note
+    /// The returned getter is cancelled if either layer is cancelled, and cancelling it cancels whichever layer is
+    /// currently pending.
+    pub fn flatten(self) -> Getter<T>
+    where
+        T: Send + 'static,
+    {
+        self.and_then(|inner| inner)
+    }
+}
+
+impl<T> FromIterator<Getter<T>> for Getter<Vec<T>>
+where
+    T: Send + 'static,
+{
+    /// Joins the futures into one that completes with all results in their original order
+    ///
+    /// # Note
+    /// The joined future is cancelled as soon as any input is cancelled, in which case the remaining inputs are cancelled
+    /// too. Dropping or cancelling the joined getter cancels all inputs. No thread is blocked while waiting for the inputs.
+    fn from_iter<I>(getters: I) -> Self
+    where
+        I: IntoIterator<Item = Getter<T>>,
+    {
+        JoinAll::start(getters, Ok, |values| values)
+    }
+}
+impl<T, E> FromIterator<Getter<Result<T, E>>> for Getter<Result<Vec<T>, E>>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    /// Joins the futures into one that completes with either all values in their original order or the first error in
+    /// completion order, mirroring `Result`'s `collect`
+    ///
+    /// # Note
+    /// On the first error, the remaining inputs are cancelled. Cancellation behaves like collecting plain getters.
+    fn from_iter<I>(getters: I) -> Self
+    where
+        I: IntoIterator<Item = Getter<Result<T, E>>>,
+    {
+        let short = |result: Result<T, E>| match result {
+            Ok(value) => Ok(Ok(value)),
+            Err(error) => Err(Err(error)),
+        };
+        JoinAll::start(getters, short, |values| values.into_iter().collect())
+    }
+}
@@ -0,0 +1,103 @@
+//! Implements a collection that yields getters in completion order
+
+use crate::{future::Getter, outcome::FutureState, signal::Signal};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Mutex},
+};
+
+/// The members of a completion set
+struct Members<T> {
+    /// The key for the next inserted getter
+    next_key: usize,
+    /// The pending getters together with their keys, in insertion order
+    getters: Vec<(usize, Getter<T>)>,
+}
+
+/// A collection of getters that yields their results in completion order
+///
+/// # Note
+/// All members notify a single shared signal, so a thread blocked in [`CompletionSet::next`] sleeps until any member
+/// is resolved. Getters can be inserted while another thread is blocked in [`CompletionSet::next`]. Dropping the set
+/// cancels all remaining members.
+pub struct CompletionSet<T> {
+    /// The shared signal that is notified if any member is resolved
+    signal: Arc<Signal>,
+    /// The members
+    members: Mutex<Members<T>>,
+}
+impl<T> CompletionSet<T> {
+    /// Creates a new empty completion set
+    pub fn new() -> Self {
+        Self { signal: Arc::default(), members: Mutex::new(Members { next_key: 0, getters: Vec::new() }) }
+    }
+
+    /// Inserts a getter into the set, returns the key that identifies its result in [`CompletionSet::next`]
+    pub fn insert(&self, getter: Getter<T>) -> usize {
+        // Register the shared signal outside of the lock since this evaluates a deferred computation, and insert the
+        // getter
+        getter.watch(&self.signal);
+        let mut members = self.members.lock().expect("The completion set is poisoned?!");
+        let key = members.next_key;
+        members.next_key += 1;
+        members.getters.push((key, getter));
+        drop(members);
+
+        // Wake a blocked thread in case the getter has been resolved already
+        self.signal.notify();
+        key
+    }
+    /// The amount of getters that have not been yielded yet
+    pub fn len(&self) -> usize {
+        self.members.lock().expect("The completion set is poisoned?!").getters.len()
+    }
+    /// Whether there are no getters left or not
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Blocks until any member is resolved, returns its key and its result (or `None` if it has been cancelled), or
+    /// returns `None` if the set is empty
+    ///
+    /// # Note
+    /// If several members are already resolved, the one that has been inserted first is picked.
+    pub fn next(&self) -> Option<(usize, Option<T>)> {
+        // Wait until any member is resolved
+        let (key, getter, remaining) = loop {
+            let mut members = self.members.lock().expect("The completion set is poisoned?!");
+            if members.getters.is_empty() {
+                return None;
+            }
+
+            // Remove the first resolved member if any
+            let resolved = members.getters.iter().position(|(_, getter)| getter.state() != FutureState::Pending);
+            if let Some(index) = resolved {
+                let (key, getter) = members.getters.remove(index);
+                break (key, getter, members.getters.len());
+            }
+
+            // Wait for further resolutions outside of the lock
+            drop(members);
+            self.signal.wait();
+        };
+
+        // Pass a possibly consumed notification on to other blocked threads
+        if remaining > 0 {
+            self.signal.notify();
+        }
+
+        // Unregister the signal and claim the result
+        getter.unwatch(&self.signal);
+        Some((key, getter.wait()))
+    }
+}
+impl<T> Default for CompletionSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> Debug for CompletionSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompletionSet").field("signal", &"<opaque>").field("len", &self.len()).finish()
+    }
+}
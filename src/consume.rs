@@ -0,0 +1,34 @@
+//! Implements the reporting of getters that are dropped without ever having been consumed
+
+use crate::id::FutureId;
+use std::sync::{Arc, RwLock};
+
+/// A hook that is invoked with the identity and the label of an unconsumed getter
+type Hook = Arc<dyn Fn(FutureId, Option<&str>) + Send + Sync>;
+
+/// The installed hook if any
+static HOOK: RwLock<Option<Hook>> = RwLock::new(None);
+
+/// Installs a process-wide hook that is invoked if a getter that must be consumed is dropped without ever having been
+/// waited on, see [`crate::FutureBuilder::must_consume`]
+///
+/// # Note
+/// The hook runs on the thread that drops the getter, and may install another hook itself. Installing another hook
+/// replaces the previous one. Without a hook, an unconsumed getter panics in debug builds and is ignored in release
+/// builds.
+pub fn set_unconsumed_hook<F>(hook: F)
+where
+    F: Fn(FutureId, Option<&str>) + Send + Sync + 'static,
+{
+    *HOOK.write().expect("The hook is poisoned?!") = Some(Arc::new(hook));
+}
+
+/// Reports an unconsumed getter to the installed hook, or panics in debug builds if no hook is installed
+pub(in crate) fn report(id: FutureId, label: Option<&str>) {
+    // Invoke the hook outside of the lock so that it can replace itself
+    let hook = HOOK.read().expect("The hook is poisoned?!").clone();
+    match hook {
+        Some(hook) => hook(id, label),
+        None => debug_assert!(false, "The getter {id:?} has been dropped without being consumed?!"),
+    }
+}
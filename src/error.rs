@@ -0,0 +1,142 @@
+//! Implements the crate's error types
+
+use std::{
+    error::Error,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+/// An error indicating that a future has been cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cancelled {
+    /// The future has been cancelled explicitly via `cancel`
+    Explicit,
+    /// The future has been cancelled because the setter has been dropped without setting a result
+    SetterDropped,
+    /// The future has been cancelled because the setter has panicked while computing the result
+    Panicked,
+    /// The future can never complete because every setter has been abandoned, see [`crate::Getter::wait_checked`]
+    NoSetter,
+}
+impl Display for Cancelled {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Explicit => write!(f, "The future has been cancelled"),
+            Self::SetterDropped => write!(f, "The future has been cancelled because the setter has been dropped"),
+            Self::Panicked => write!(f, "The future has been cancelled because the setter has panicked"),
+            Self::NoSetter => write!(f, "The future has been cancelled because no setter is left"),
+        }
+    }
+}
+impl Error for Cancelled {
+    // No members to override
+}
+
+/// An error indicating that one of several joined futures has been cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JoinError {
+    /// The index of the cancelled future
+    pub index: usize,
+}
+impl Display for JoinError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "The future at index {} has been cancelled", self.index)
+    }
+}
+impl Error for JoinError {
+    // No members to override
+}
+
+/// An error indicating that a result could not be set; carries the original value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SetError<T> {
+    /// The future has been cancelled
+    Cancelled {
+        /// The value that could not be set
+        value: T,
+        /// Why the future has been cancelled
+        reason: Cancelled,
+    },
+    /// The future has already been set by another setter
+    AlreadySet(T),
+}
+impl<T> SetError<T> {
+    /// Recovers the value that could not be set
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Cancelled { value, .. } => value,
+            Self::AlreadySet(value) => value,
+        }
+    }
+}
+impl<T> Display for SetError<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Cancelled { reason, .. } => write!(f, "The result could not be set: {reason}"),
+            Self::AlreadySet(_) => write!(f, "The result could not be set: The future has already been set"),
+        }
+    }
+}
+impl<T> Error for SetError<T>
+where
+    T: Debug,
+{
+    // No members to override
+}
+
+/// The reason why a future with typed cancellation reasons has been cancelled, see [`crate::with_reason`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CancelReason<R> {
+    /// The future has been cancelled implicitly because either half has been dropped
+    Dropped,
+    /// The future has been cancelled explicitly with a reason
+    Reason(R),
+}
+impl<R> Display for CancelReason<R>
+where
+    R: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Dropped => write!(f, "The future has been cancelled because it has been dropped"),
+            Self::Reason(reason) => write!(f, "The future has been cancelled: {reason}"),
+        }
+    }
+}
+impl<R> Error for CancelReason<R>
+where
+    R: Debug + Display,
+{
+    // No members to override
+}
+
+/// An error indicating why a future that carries a `Result` did not complete with `Ok`, see [`crate::Getter::wait_ok`]
+/// and [`crate::ResultGetter::wait`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FutureError<E> {
+    /// The future has been cancelled
+    Cancelled(Cancelled),
+    /// The future has completed with an error
+    Failed(E),
+}
+impl<E> Display for FutureError<E>
+where
+    E: Display,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Cancelled(reason) => write!(f, "{reason}"),
+            Self::Failed(error) => write!(f, "The future has failed: {error}"),
+        }
+    }
+}
+impl<E> Error for FutureError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Failed(error) => Some(error),
+            Self::Cancelled(_) => None,
+        }
+    }
+}
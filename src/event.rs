@@ -0,0 +1,78 @@
+//! Implements a manual-reset event
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Condvar, Mutex},
+    time::Duration,
+};
+
+/// The state of an event
+#[derive(Debug, Default)]
+struct State {
+    /// Whether the event is set or not
+    set: bool,
+    /// The amount of times the event has been set, so that a waiter is released even if the event is reset before it
+    /// wakes up
+    generation: u64,
+}
+
+/// A manual-reset event that releases all current and future waiters once it is set, until it is reset
+///
+/// # Note
+/// Every thread that is waiting at the time the event is set is released, even if the event is reset before the thread
+/// wakes up. Share the event via an [`std::sync::Arc`] or a scoped borrow.
+#[derive(Default)]
+pub struct Event {
+    /// The state
+    state: Mutex<State>,
+    /// The signal variable
+    signal: Condvar,
+}
+impl Event {
+    /// Creates a new event that is not set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the event and releases all waiting threads
+    pub fn set(&self) {
+        let mut state = self.state.lock().expect("The event is poisoned?!");
+        if !state.set {
+            state.set = true;
+            state.generation = state.generation.wrapping_add(1);
+            self.signal.notify_all();
+        }
+    }
+    /// Resets the event so that subsequent waits block again
+    pub fn reset(&self) {
+        self.state.lock().expect("The event is poisoned?!").set = false;
+    }
+    /// Whether the event is set or not
+    pub fn is_set(&self) -> bool {
+        self.state.lock().expect("The event is poisoned?!").set
+    }
+
+    /// Blocks until the event is set
+    pub fn wait(&self) {
+        let state = self.state.lock().expect("The event is poisoned?!");
+        let generation = state.generation;
+        let _state = (self.signal)
+            .wait_while(state, |state| !state.set && state.generation == generation)
+            .expect("The event is poisoned?!");
+    }
+    /// Blocks until the event is set or the timeout is reached, returns whether the event has been set
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let state = self.state.lock().expect("The event is poisoned?!");
+        let generation = state.generation;
+        let (state, _) = (self.signal)
+            .wait_timeout_while(state, timeout, |state| !state.set && state.generation == generation)
+            .expect("The event is poisoned?!");
+        state.set || state.generation != generation
+    }
+}
+impl Debug for Event {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let set = self.state.lock().ok().map(|state| state.set);
+        f.debug_struct("Event").field("set", &set).field("signal", &"<opaque>").finish()
+    }
+}
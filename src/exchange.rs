@@ -0,0 +1,86 @@
+//! Implements a rendezvous where two threads meet and swap values
+
+use crate::{
+    future::{Getter, Setter},
+    outcome::CancelOutcome,
+};
+use std::time::Duration;
+
+/// Delivers `value` to the other side and waits for the value of the other side, returns `None` if the other side has
+/// been dropped or the timeout is reached
+///
+/// # Note
+/// Either both sides receive the value of the other side, or neither does: a side only waits if its own value has been
+/// delivered, and a side that times out only gives up if it can still withdraw from the exchange.
+fn swap<G, T>(setter: Setter<G>, getter: Getter<T>, value: G, timeout: Option<Duration>) -> Option<T> {
+    // Deliver our value; this fails if the other side has been dropped or has given up already
+    setter.try_set(value).ok()?;
+    let Some(timeout) = timeout else {
+        return getter.wait();
+    };
+
+    // Wait for the value of the other side
+    let getter = match getter.wait_timeout(timeout) {
+        Ok(result) => return result,
+        Err(getter) => getter,
+    };
+
+    // Withdraw from the exchange unless the other side has delivered its value in the meantime
+    match getter.try_cancel() {
+        CancelOutcome::AlreadySet => getter.wait(),
+        CancelOutcome::Cancelled | CancelOutcome::AlreadyCancelled => None,
+    }
+}
+
+/// The left side of an exchange that gives an `A` and takes a `B`, see [`exchange`]
+#[derive(Debug)]
+pub struct Left<A, B> {
+    /// The setter for the value that is given to the right side
+    setter: Setter<A>,
+    /// The getter for the value that is taken from the right side
+    getter: Getter<B>,
+}
+impl<A, B> Left<A, B> {
+    /// Blocks until the right side exchanges its value, returns either the value of the right side or `None` if the
+    /// right side has been dropped
+    pub fn exchange(self, value: A) -> Option<B> {
+        swap(self.setter, self.getter, value, None)
+    }
+    /// Blocks until the right side exchanges its value or the timeout is reached, returns either the value of the right
+    /// side or `None` if the right side has been dropped or the timeout has been reached
+    pub fn exchange_timeout(self, value: A, timeout: Duration) -> Option<B> {
+        swap(self.setter, self.getter, value, Some(timeout))
+    }
+}
+
+/// The right side of an exchange that gives a `B` and takes an `A`, see [`exchange`]
+#[derive(Debug)]
+pub struct Right<B, A> {
+    /// The setter for the value that is given to the left side
+    setter: Setter<B>,
+    /// The getter for the value that is taken from the left side
+    getter: Getter<A>,
+}
+impl<B, A> Right<B, A> {
+    /// Blocks until the left side exchanges its value, returns either the value of the left side or `None` if the left
+    /// side has been dropped
+    pub fn exchange(self, value: B) -> Option<A> {
+        swap(self.setter, self.getter, value, None)
+    }
+    /// Blocks until the left side exchanges its value or the timeout is reached, returns either the value of the left side
+    /// or `None` if the left side has been dropped or the timeout has been reached
+    pub fn exchange_timeout(self, value: B, timeout: Duration) -> Option<A> {
+        swap(self.setter, self.getter, value, Some(timeout))
+    }
+}
+
+/// Creates a new rendezvous where two threads meet and swap an `A` for a `B`
+///
+/// # Note
+/// Each side can exchange exactly once. The exchange is all-or-nothing: if one side times out or is dropped, the other
+/// side does not receive a value either.
+pub fn exchange<A, B>() -> (Left<A, B>, Right<B, A>) {
+    let (setter_a, getter_a) = crate::new();
+    let (setter_b, getter_b) = crate::new();
+    (Left { setter: setter_a, getter: getter_b }, Right { setter: setter_b, getter: getter_a })
+}
@@ -0,0 +1,149 @@
+//! Implements a minimal thread-pool executor that hands out getters for submitted jobs
+
+use crate::future::Getter;
+use std::{
+    collections::VecDeque,
+    fmt::{self, Debug, Formatter},
+    mem,
+    sync::{Arc, Condvar, Mutex},
+    thread::{self, JoinHandle},
+};
+
+/// A queued job
+type Job = Box<dyn FnOnce() + Send>;
+
+/// The queue of jobs that have not been started yet
+#[derive(Default)]
+struct Queue {
+    /// The queued jobs in submission order
+    jobs: VecDeque<Job>,
+    /// Whether the executor has been shut down or not
+    shutdown: bool,
+}
+
+/// The state that is shared with the worker threads
+#[derive(Default)]
+struct Inner {
+    /// The queue of jobs
+    queue: Mutex<Queue>,
+    /// The signal to wake idle workers if a job is queued or the executor is shut down
+    signal: Condvar,
+}
+impl Inner {
+    /// Runs queued jobs until the executor is shut down
+    fn run(&self) {
+        loop {
+            // Wait for the next job
+            let queue = self.queue.lock().expect("The executor is poisoned?!");
+            let mut queue = self
+                .signal
+                .wait_while(queue, |queue| queue.jobs.is_empty() && !queue.shutdown)
+                .expect("The executor is poisoned?!");
+            let Some(job) = queue.jobs.pop_front() else {
+                return;
+            };
+
+            // Run the job outside of the lock
+            drop(queue);
+            job();
+        }
+    }
+}
+
+/// A minimal thread-pool executor with a fixed amount of worker threads
+///
+/// # Note
+/// Jobs whose getter has been cancelled before they are started are skipped, and a panicking job cancels its getter
+/// without killing the worker. Dropping the executor shuts it down, see [`Executor::shutdown`].
+pub struct Executor {
+    /// The shared state
+    inner: Arc<Inner>,
+    /// The worker threads
+    workers: Mutex<Vec<JoinHandle<()>>>,
+}
+impl Executor {
+    /// Creates a new executor with `num_threads` worker threads
+    ///
+    /// # Panics
+    /// This function panics if `num_threads` is zero or if a worker thread cannot be spawned.
+    pub fn new(num_threads: usize) -> Self {
+        assert!(num_threads > 0, "An executor needs at least one worker thread?!");
+
+        // Spawn the workers
+        let inner = Arc::new(Inner::default());
+        let workers = (0..num_threads)
+            .map(|index| {
+                let inner = inner.clone();
+                thread::Builder::new()
+                    .name(format!("tiny_future-executor-{index}"))
+                    .spawn(move || inner.run())
+                    .expect("Failed to spawn executor thread")
+            })
+            .collect();
+        Self { inner, workers: Mutex::new(workers) }
+    }
+
+    /// The amount of jobs that have been submitted but not started yet
+    pub fn queued(&self) -> usize {
+        self.inner.queue.lock().expect("The executor is poisoned?!").jobs.len()
+    }
+
+    /// Submits `f` to be executed on a worker thread, returns a getter for its result
+    ///
+    /// # Note
+    /// If the executor has already been shut down, the returned future is cancelled immediately.
+    pub fn submit<T, F>(&self, f: F) -> Getter<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (setter, getter) = crate::new();
+        let job: Job = Box::new(move || {
+            // Skip the job entirely if the getter has lost interest before it has been started
+            if !setter.is_cancelled() {
+                setter.complete_with(f);
+            }
+        });
+
+        // Queue the job unless the executor has been shut down
+        let mut queue = self.inner.queue.lock().expect("The executor is poisoned?!");
+        if !queue.shutdown {
+            queue.jobs.push_back(job);
+            self.inner.signal.notify_one();
+        }
+        getter
+    }
+
+    /// Shuts the executor down, cancels all queued jobs that have not been started yet, and waits until the running jobs
+    /// have finished
+    ///
+    /// # Note
+    /// Calling this function from within a job deadlocks, since the worker would wait for itself.
+    pub fn shutdown(&self) {
+        // Take the queued jobs and wake all workers
+        let mut queue = self.inner.queue.lock().expect("The executor is poisoned?!");
+        queue.shutdown = true;
+        let jobs = mem::take(&mut queue.jobs);
+        self.inner.signal.notify_all();
+        drop(queue);
+
+        // Cancel the queued jobs outside of the lock and wait for the workers
+        drop(jobs);
+        let workers = mem::take(&mut *self.workers.lock().expect("The executor is poisoned?!"));
+        for worker in workers {
+            // Jobs cannot panic the workers, so a failed join only means that a worker has been torn down already
+            let _ = worker.join();
+        }
+    }
+}
+impl Debug for Executor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let workers = self.workers.lock().map(|workers| workers.len()).unwrap_or_default();
+        f.debug_struct("Executor").field("workers", &workers).field("queued", &self.queued()).finish()
+    }
+}
+impl Drop for Executor {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
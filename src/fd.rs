@@ -0,0 +1,77 @@
+//! Implements a pollable file descriptor that becomes readable once a future is resolved
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    os::fd::{AsFd, BorrowedFd, FromRawFd, OwnedFd},
+};
+
+/// A file descriptor that becomes readable once it has been signalled
+///
+/// # Note
+/// The descriptor is an `eventfd` on Linux and Android, and the read end of a pipe on other Unix platforms. It is never
+/// drained, so it stays readable once it has been signalled.
+pub(in crate) struct EventFd {
+    /// The descriptor that becomes readable
+    read: OwnedFd,
+    /// The descriptor to write to, which is the same descriptor for an `eventfd`
+    write: File,
+}
+impl EventFd {
+    /// Creates a new, non-signalled descriptor
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn new() -> io::Result<Self> {
+        // Create a non-blocking eventfd that is not inherited by child processes
+        // SAFETY: `eventfd` takes no pointers and only creates a new descriptor
+        let fd = unsafe { libc::eventfd(0, libc::EFD_CLOEXEC | libc::EFD_NONBLOCK) };
+        if fd == -1 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Take ownership and duplicate the descriptor for writing
+        // SAFETY: `fd` is a valid descriptor that has just been created and is not owned by anything else
+        let read = unsafe { OwnedFd::from_raw_fd(fd) };
+        let write = File::from(read.try_clone()?);
+        Ok(Self { read, write })
+    }
+    /// Creates a new, non-signalled descriptor
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub fn new() -> io::Result<Self> {
+        // Create a pipe and take ownership of both ends
+        let mut fds = [0; 2];
+        // SAFETY: `fds` is a valid, writable buffer for the two descriptors that `pipe` stores
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } == -1 {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: Both descriptors are valid since `pipe` has succeeded, and are not owned by anything else
+        let (read, write) = unsafe { (OwnedFd::from_raw_fd(fds[0]), OwnedFd::from_raw_fd(fds[1])) };
+
+        // Make both ends non-blocking and not inherited by child processes
+        for fd in [&read, &write] {
+            let fd = std::os::fd::AsRawFd::as_raw_fd(fd);
+            // SAFETY: `fd` is borrowed from an owned descriptor that stays open, and none of the commands takes a pointer
+            let is_configured = unsafe {
+                let flags = libc::fcntl(fd, libc::F_GETFL);
+                flags != -1
+                    && libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK) != -1
+                    && libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC) != -1
+            };
+            if !is_configured {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(Self { read, write: File::from(write) })
+    }
+
+    /// Makes the descriptor readable
+    pub fn signal(&self) {
+        // An eventfd expects an 8-byte counter increment, which a pipe accepts as well; a full pipe or a saturated
+        // counter is readable already, so the error can be ignored
+        let increment = 1u64.to_ne_bytes();
+        let _ = (&self.write).write(&increment);
+    }
+    /// The descriptor that becomes readable
+    pub fn as_fd(&self) -> BorrowedFd<'_> {
+        self.read.as_fd()
+    }
+}
@@ -1,38 +1,505 @@
 //! Implements the future
 
+use crate::{
+    consume,
+    error::{Cancelled, SetError},
+    id::FutureId,
+    interrupt::{Interrupter, Notify},
+    outcome::{CancelOutcome, DeadlineOutcome, FutureState, InterruptOutcome, JoinOutcome, WaitOutcome},
+    pool::Recycler,
+    progress::Report,
+    signal::Signal,
+    timer::{ScheduledCancel, TimerEntry},
+    timing::Timing,
+    token::Cancel,
+};
 use std::{
+    any::Any,
+    ffi::c_void,
     fmt::{self, Debug, Formatter},
+    hint, mem,
+    ops::{ControlFlow, Deref},
+    panic::{self, AssertUnwindSafe},
     sync::{
-        atomic::{AtomicBool, Ordering::SeqCst},
-        Arc, Condvar, Mutex,
+        atomic::{AtomicBool, AtomicUsize, Ordering::SeqCst},
+        Arc, Condvar, Mutex, MutexGuard, OnceLock, TryLockError, Weak,
     },
-    time::Duration,
+    thread,
+    time::{Duration, Instant},
 };
+#[cfg(all(unix, feature = "event-fd"))]
+use crate::fd::EventFd;
+#[cfg(all(unix, feature = "event-fd"))]
+use std::os::fd::BorrowedFd;
+#[cfg(all(windows, feature = "windows"))]
+use crate::win32::Win32Event;
+#[cfg(all(windows, feature = "windows"))]
+use std::os::windows::io::RawHandle;
+#[cfg(feature = "async")]
+use std::task::Waker;
+
+/// The waker slot of a task that awaits the cancellation of the future, see [`Setter::cancelled`]
+#[cfg(feature = "async")]
+pub(in crate) type WakerSlot = Mutex<Option<Waker>>;
+/// A completion callback that is invoked with the result or `None` on cancellation
+type Callback<T> = Box<dyn FnOnce(Option<T>) + Send>;
+/// A hook that is invoked if the future is cancelled from the getter side
+type CancelHook = Box<dyn FnOnce() + Send>;
+/// A hook that is invoked with a reference to the result once it is claimed
+type Inspector<T> = Box<dyn FnOnce(&T) + Send>;
+/// The payload of a panic
+type PanicPayload = Box<dyn Any + Send>;
+/// A deferred computation that completes the future once it is evaluated
+type Deferred = Box<dyn FnOnce() + Send>;
+
+/// The latest progress of a future
+#[derive(Default)]
+struct Progress {
+    /// The latest progress if any
+    value: Option<Box<dyn Any + Send>>,
+    /// The amount of progress reports so far
+    version: u64,
+}
+
+/// The state of a future
+///
+/// # Note
+/// Every state transition happens under the lock of the future, so a future ends up in exactly one terminal state. To
+/// the getter, a future whose result has been claimed looks cancelled, since the result cannot be claimed a second time.
+enum State<T> {
+    /// The future has neither completed nor been cancelled yet
+    Pending,
+    /// The future has completed and the result has not been claimed yet
+    Set(T),
+    /// The future has completed and the result has been claimed
+    Claimed,
+    /// The future has been cancelled
+    Cancelled(Cancelled),
+    /// The setter has panicked and the payload has not been claimed yet
+    Panicked(PanicPayload),
+}
+impl<T> State<T> {
+    /// Whether the future is still pending or not
+    const fn is_pending(&self) -> bool {
+        matches!(self, Self::Pending)
+    }
+}
+impl<T> Debug for State<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => f.write_str("Pending"),
+            Self::Set(_) => f.debug_tuple("Set").field(&"<opaque>").finish(),
+            Self::Claimed => f.write_str("Claimed"),
+            Self::Cancelled(reason) => f.debug_tuple("Cancelled").field(reason).finish(),
+            Self::Panicked(_) => f.debug_tuple("Panicked").field(&"<opaque>").finish(),
+        }
+    }
+}
+
+/// A guard that borrows the result of a future, see [`Getter::wait_ref`]
+///
+/// # Note
+/// The guard holds the lock of the future, so other operations on the future block until the guard is dropped.
+pub struct ValueGuard<'a, T> {
+    /// The locked state which is guaranteed to hold a result
+    state: MutexGuard<'a, State<T>>,
+}
+impl<T> Deref for ValueGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match &*self.state {
+            State::Set(value) => value,
+            _ => unreachable!("The guarded result is missing?!"),
+        }
+    }
+}
+impl<T> Debug for ValueGuard<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValueGuard").field("result", &**self).finish()
+    }
+}
+
+/// Registers a thread as blocked waiting on a future for the lifetime of the guard
+struct WaiterGuard<'a> {
+    /// The amount of waiting threads
+    waiters: &'a AtomicUsize,
+}
+impl<'a> WaiterGuard<'a> {
+    /// Registers a waiting thread
+    fn new(waiters: &'a AtomicUsize) -> Self {
+        waiters.fetch_add(1, SeqCst);
+        Self { waiters }
+    }
+}
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        self.waiters.fetch_sub(1, SeqCst);
+    }
+}
 
 /// The inner state of the future
+///
+/// # Lock order
+/// If several locks are needed, `state` must always be acquired first.
 pub struct Future<T> {
+    /// The identity token of the future
+    id: FutureId,
     /// The signal variable
     signal: Condvar,
-    /// The result
-    result: Mutex<Option<T>>,
+    /// The state
+    state: Mutex<State<T>>,
+    /// The amount of live setters
+    setters: AtomicUsize,
+    /// Whether the getter still exists or not
+    getter_alive: AtomicBool,
+    /// The amount of threads that are currently blocked waiting on the future
+    waiters: AtomicUsize,
+    /// Whether the getter has been detached or not
+    detached: AtomicBool,
+    /// The completion callback if any
+    callback: Mutex<Option<Callback<T>>>,
+    /// The hooks to invoke if the future is cancelled from the getter side
+    cancel_hooks: Mutex<Vec<CancelHook>>,
+    /// The shared signals to notify if the future is resolved
+    watchers: Mutex<Vec<Arc<Signal>>>,
+    /// The completion timing
+    timing: Timing,
+    /// The hooks to invoke once the result is claimed, in registration order
+    inspectors: Mutex<Vec<Inspector<T>>>,
+    /// The fallback result to set if the last setter is dropped without setting a result
+    fallback: Mutex<Option<T>>,
+    /// The deadline announced by the setter if any
+    deadline: Mutex<Option<Instant>>,
+    /// The deferred computation to evaluate on the first wait if any
+    deferred: Mutex<Option<Deferred>>,
+    /// A human-readable label of the producer if any
+    label: OnceLock<String>,
+    /// The latest progress reported by the producer
+    progress: Mutex<Progress>,
+    /// The amount of live setters and getters
+    handles: AtomicUsize,
+    /// The pool to return the future to once all handles have been dropped if any
+    pool: Option<Weak<Recycler<T>>>,
+    /// Whether dropping the getter without consuming it is reported or not
+    must_consume: AtomicBool,
+    /// Whether the getter has been waited on or polled
+    consumed: AtomicBool,
+    /// The waker of the task that awaits the getter if any
+    #[cfg(feature = "async")]
+    waker: Mutex<Option<Waker>>,
+    /// The wakers of the tasks that await the cancellation of the future, see [`Setter::cancelled`]
+    #[cfg(feature = "async")]
+    cancel_wakers: Mutex<Vec<Weak<WakerSlot>>>,
+    /// The file descriptor that becomes readable once the future is resolved, created on first use
+    #[cfg(all(unix, feature = "event-fd"))]
+    event_fd: OnceLock<EventFd>,
+    /// The Win32 event that is set once the future is resolved, created on first use
+    #[cfg(all(windows, feature = "windows"))]
+    win32_event: OnceLock<Win32Event>,
 }
 impl<T> Future<T> {
     /// Creates a new inner state of the future
     pub fn new() -> Self {
-        Self { signal: Condvar::new(), result: Mutex::default() }
+        Self {
+            id: FutureId::next(),
+            signal: Condvar::new(),
+            state: Mutex::new(State::Pending),
+            setters: AtomicUsize::default(),
+            getter_alive: AtomicBool::new(true),
+            waiters: AtomicUsize::default(),
+            detached: AtomicBool::default(),
+            callback: Mutex::default(),
+            cancel_hooks: Mutex::default(),
+            watchers: Mutex::default(),
+            timing: Timing::new(),
+            inspectors: Mutex::default(),
+            fallback: Mutex::default(),
+            deadline: Mutex::default(),
+            deferred: Mutex::default(),
+            label: OnceLock::new(),
+            progress: Mutex::default(),
+            handles: AtomicUsize::default(),
+            pool: None,
+            must_consume: AtomicBool::default(),
+            consumed: AtomicBool::default(),
+            #[cfg(feature = "async")]
+            waker: Mutex::default(),
+            #[cfg(feature = "async")]
+            cancel_wakers: Mutex::default(),
+            #[cfg(all(unix, feature = "event-fd"))]
+            event_fd: OnceLock::new(),
+            #[cfg(all(windows, feature = "windows"))]
+            win32_event: OnceLock::new(),
+        }
+    }
+    /// Creates a new inner state of the future that is returned to `pool` once all handles have been dropped
+    pub(in crate) fn pooled(pool: Weak<Recycler<T>>) -> Self {
+        Self { pool: Some(pool), ..Self::new() }
+    }
+
+    /// Claims the result if the future is resolved, returns either `Some(Ok(result))` if the future has completed
+    /// successfully, `Some(Err(reason))` if the future has been cancelled, or `None` if the future is still pending
+    fn claim(state: &mut State<T>, cancelled: &AtomicBool) -> Option<Result<T, Cancelled>> {
+        match mem::replace(state, State::Claimed) {
+            State::Pending => {
+                *state = State::Pending;
+                None
+            }
+            State::Set(value) => {
+                // Flag the future as cancelled since the result cannot be claimed a second time
+                cancelled.store(true, SeqCst);
+                Some(Ok(value))
+            }
+            State::Claimed => Some(Err(Cancelled::Explicit)),
+            State::Cancelled(reason) => {
+                *state = State::Cancelled(reason);
+                Some(Err(reason))
+            }
+            State::Panicked(payload) => {
+                *state = State::Panicked(payload);
+                Some(Err(Cancelled::Panicked))
+            }
+        }
+    }
+    /// Claims the panic payload if the setter has panicked
+    fn claim_panic(state: &mut State<T>) -> Option<PanicPayload> {
+        match mem::replace(state, State::Cancelled(Cancelled::Panicked)) {
+            State::Panicked(payload) => Some(payload),
+            other => {
+                *state = other;
+                None
+            }
+        }
+    }
+    /// Invokes the inspectors in registration order if the result has been claimed
+    fn inspect_claimed(&self, value: Option<&T>) {
+        let Some(value) = value else {
+            return;
+        };
+
+        // Take the inspectors and invoke them outside of the lock
+        let inspectors = mem::take(&mut *self.inspectors.lock().expect("The future is poisoned?!"));
+        for inspector in inspectors {
+            inspector(value);
+        }
+    }
+    /// Unregisters a setter or getter and returns the future to its pool if it was the last handle
+    fn release(self: &Arc<Self>, cancelled: &Arc<AtomicBool>) {
+        // Only the last handle returns the future
+        let is_last = self.handles.fetch_sub(1, SeqCst) == 1;
+        let Some(pool) = self.pool.as_ref().and_then(Weak::upgrade).filter(|_| is_last) else {
+            return;
+        };
+
+        // Drop an unclaimed result right away instead of keeping it alive until the future is reused
+        let stale = mem::replace(&mut *self.state.lock().expect("The future is poisoned?!"), State::Claimed);
+        drop(stale);
+        pool.recycle(self.clone(), cancelled.clone());
+    }
+    /// Notifies all shared signals that watch this future, and wakes the task that awaits the getter if any
+    fn notify_watchers(&self) {
+        let watchers = self.watchers.lock().expect("The future is poisoned?!");
+        for watcher in watchers.iter() {
+            watcher.notify();
+        }
+
+        // Wake the task outside of the lock
+        drop(watchers);
+        #[cfg(feature = "async")]
+        if let Some(waker) = self.waker.lock().expect("The future is poisoned?!").take() {
+            waker.wake();
+        }
+        #[cfg(feature = "async")]
+        for slot in mem::take(&mut *self.cancel_wakers.lock().expect("The future is poisoned?!")) {
+            let waker = slot.upgrade().and_then(|slot| slot.lock().expect("The future is poisoned?!").take());
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+        #[cfg(all(unix, feature = "event-fd"))]
+        if let Some(event_fd) = self.event_fd.get() {
+            event_fd.signal();
+        }
+        #[cfg(all(windows, feature = "windows"))]
+        if let Some(win32_event) = self.win32_event.get() {
+            win32_event.signal();
+        }
+    }
+
+    /// Sets the result if the future is still pending, or hands the value back
+    fn set(&self, value: T) -> Result<(), SetError<T>> {
+        let state = self.state.lock().expect("The future is poisoned?!");
+        self.set_locked(state, value)
+    }
+    /// Sets the result under the already acquired lock if the future is still pending, or hands the value back
+    fn set_locked(&self, mut state: MutexGuard<State<T>>, value: T) -> Result<(), SetError<T>> {
+        // Only do something if the future is still pending
+        match *state {
+            State::Pending => (),
+            State::Set(_) | State::Claimed => return Err(SetError::AlreadySet(value)),
+            State::Cancelled(reason) => return Err(SetError::Cancelled { value, reason }),
+            State::Panicked(_) => return Err(SetError::Cancelled { value, reason: Cancelled::Panicked }),
+        }
+
+        // Record the completion time, take the completion callback if any, and discard the cancellation hooks since the
+        // future cannot be cancelled anymore
+        self.timing.complete();
+        let callback = self.callback.lock().expect("The future is poisoned?!").take();
+        let hooks = mem::take(&mut *self.cancel_hooks.lock().expect("The future is poisoned?!"));
+
+        // Set the result; if a completion callback is registered or the getter has been detached, the result is
+        // handed to the callback or dropped respectively since nobody can claim it anymore
+        let value = match (callback.is_some(), self.detached.load(SeqCst)) {
+            (true, _) | (false, true) => {
+                *state = State::Claimed;
+                Some(value)
+            }
+            (false, false) => {
+                *state = State::Set(value);
+                None
+            }
+        };
+
+        // Wake waiting threads and hand the result to the completion callback outside of the lock
+        drop((state, hooks));
+        self.signal.notify_all();
+        self.notify_watchers();
+        if let (Some(callback), Some(value)) = (callback, value) {
+            self.inspect_claimed(Some(&value));
+            callback(Some(value));
+        }
+        Ok(())
+    }
+    /// Cancels the future if it is still pending and wakes waiting threads, returns the cancellation hooks if the future
+    /// has been cancelled by this call
+    fn cancel(&self, cancelled: &AtomicBool, reason: Cancelled) -> Result<Vec<CancelHook>, CancelOutcome> {
+        self.terminate(cancelled, State::Cancelled(reason))
+    }
+    /// Moves the future into a terminal state without result if it is still pending and wakes waiting threads, returns
+    /// either the cancellation hooks if the future has been terminated by this call, or why the future has not been
+    /// terminated
+    fn terminate(&self, cancelled: &AtomicBool, terminal: State<T>) -> Result<Vec<CancelHook>, CancelOutcome> {
+        // Only terminate the future if it is still pending
+        let mut state = self.state.lock().expect("The future is poisoned?!");
+        match *state {
+            State::Pending => (),
+            State::Set(_) | State::Claimed => return Err(CancelOutcome::AlreadySet),
+            State::Cancelled(_) | State::Panicked(_) => return Err(CancelOutcome::AlreadyCancelled),
+        }
+
+        // Terminate the future and take the completion callback and the hooks
+        *state = terminal;
+        cancelled.store(true, SeqCst);
+        self.timing.complete();
+        let callback = self.callback.lock().expect("The future is poisoned?!").take();
+        let hooks = mem::take(&mut *self.cancel_hooks.lock().expect("The future is poisoned?!"));
+        let deferred = self.deferred.lock().expect("The future is poisoned?!").take();
+
+        // Wake waiting threads and notify the completion callback outside of the lock
+        drop(state);
+        drop(deferred);
+        self.signal.notify_all();
+        self.notify_watchers();
+        if let Some(callback) = callback {
+            callback(None);
+        }
+        Ok(hooks)
+    }
+    /// Records that the getter has been waited on and evaluates the deferred computation if any on the current thread
+    fn evaluate(&self) {
+        self.consumed.store(true, SeqCst);
+        self.run_deferred();
+    }
+    /// Evaluates the deferred computation if any on the current thread
+    ///
+    /// # Note
+    /// This must not be called while a lock is held, since the deferred computation completes the future.
+    fn run_deferred(&self) {
+        let deferred = self.deferred.lock().expect("The future is poisoned?!").take();
+        if let Some(deferred) = deferred {
+            deferred();
+        }
+    }
+    /// Cancels the future from the getter side if it is still pending and invokes the cancellation hooks, returns the
+    /// outcome of the cancellation
+    fn cancel_getter(&self, cancelled: &AtomicBool) -> CancelOutcome {
+        let hooks = match self.cancel(cancelled, Cancelled::Explicit) {
+            Ok(hooks) => hooks,
+            Err(outcome) => return outcome,
+        };
+        for hook in hooks {
+            hook();
+        }
+        CancelOutcome::Cancelled
+    }
+}
+impl<T> Notify for Future<T>
+where
+    T: Send,
+{
+    fn notify(&self) {
+        // Acquire the lock so that no waiting thread can miss the notification between checking its condition and
+        // going to sleep
+        let _state = self.state.lock().expect("The future is poisoned?!");
+        self.signal.notify_all();
+    }
+}
+impl<T> Report for Future<T>
+where
+    T: Send,
+{
+    fn report(&self, progress: Box<dyn Any + Send>) {
+        // Ignore progress reports once the future has been resolved
+        let state = self.state.lock().expect("The future is poisoned?!");
+        if !state.is_pending() {
+            return;
+        }
+
+        // Replace the progress and wake waiting threads; the previous progress is dropped outside of the lock
+        let mut current = self.progress.lock().expect("The future is poisoned?!");
+        let previous = current.value.replace(progress);
+        current.version += 1;
+        drop((current, state));
+        self.signal.notify_all();
+        drop(previous);
     }
 }
 impl<T> Debug for Future<T> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        // Get a debug representation for the result
-        let result: &dyn Debug = match self.result.lock() {
-            Ok(result) if result.is_some() => &Some("<opaque>"),
-            Ok(_) => &Option::<&str>::None,
-            Err(_) => &"<poisoned>",
+        // Get a debug representation for the state
+        let state = match self.state.lock() {
+            Ok(state) => format!("{:?}", *state),
+            Err(_) => "<poisoned>".to_string(),
         };
 
         // Debug-format the struct
-        f.debug_struct("Future").field("signal", &"<opaque>").field("result", &result).finish()
+        f.debug_struct("Future")
+            .field("id", &self.id)
+            .field("signal", &"<opaque>")
+            .field("state", &format_args!("{state}"))
+            .field("setters", &self.setters.load(SeqCst))
+            .field("getter_alive", &self.getter_alive.load(SeqCst))
+            .field("waiters", &self.waiters.load(SeqCst))
+            .field("detached", &self.detached.load(SeqCst))
+            .field("callback", &"<opaque>")
+            .field("cancel_hooks", &"<opaque>")
+            .field("watchers", &"<opaque>")
+            .field("timing", &self.timing)
+            .field("inspectors", &"<opaque>")
+            .field("fallback", &"<opaque>")
+            .field("deadline", &self.deadline.lock().ok().map(|deadline| *deadline))
+            .field("deferred", &"<opaque>")
+            .field("label", &self.label.get())
+            .field("progress", &self.progress.lock().ok().map(|progress| progress.version))
+            .field("handles", &self.handles.load(SeqCst))
+            .field("pool", &self.pool.is_some())
+            .field("must_consume", &self.must_consume.load(SeqCst))
+            .field("consumed", &self.consumed.load(SeqCst))
+            .finish()
     }
 }
 
@@ -42,43 +509,438 @@ pub struct Setter<T> {
     future: Arc<Future<T>>,
     /// Whether the future has been cancelled or not
     cancelled: Arc<AtomicBool>,
+    /// Whether the setter has been abandoned and must not cancel the future on drop
+    abandoned: bool,
 }
 impl<T> Setter<T> {
     /// Creates a new setter
-    pub(in crate) const fn new(future: Arc<Future<T>>, cancelled: Arc<AtomicBool>) -> Self {
-        Self { future, cancelled }
+    pub(in crate) fn new(future: Arc<Future<T>>, cancelled: Arc<AtomicBool>) -> Self {
+        // Register the setter as live
+        future.setters.fetch_add(1, SeqCst);
+        future.handles.fetch_add(1, SeqCst);
+        Self { future, cancelled, abandoned: false }
     }
 
+    /// The identity token that is shared with the corresponding getter
+    pub fn id(&self) -> FutureId {
+        self.future.id
+    }
+    /// Whether a thread is currently blocked waiting on the future or not, see [`Setter::waiter_count`]
+    pub fn has_waiter(&self) -> bool {
+        self.waiter_count() > 0
+    }
+    /// The amount of threads that are currently blocked waiting on the future (e.g. via [`Getter::wait`] or
+    /// [`Getter::wait_timeout`])
+    ///
+    /// # Note
+    /// This is racy by nature: a thread may start or stop waiting right after the count has been read. It only reports
+    /// threads that are blocked right now, not whether the getter still exists.
+    pub fn waiter_count(&self) -> usize {
+        self.future.waiters.load(SeqCst)
+    }
+    /// Whether the getter still exists or not
+    ///
+    /// # Note
+    /// Unlike [`Setter::is_cancelled`], this does not tell why the getter is gone: it returns `false` both if the getter
+    /// has been dropped without claiming the result and if it has been consumed by claiming the result or detaching. A
+    /// getter that has been converted via [`Getter::into_raw`] is still alive.
+    pub fn is_getter_alive(&self) -> bool {
+        self.future.getter_alive.load(SeqCst)
+    }
     /// Whether the future has been cancelled or not
     pub fn is_cancelled(&self) -> bool {
         self.cancelled.load(SeqCst)
     }
+    /// The current state of the future as seen by the setter
+    ///
+    /// # Note
+    /// Unlike [`Getter::state`], this reports [`FutureState::Ready`] even if the result has already been claimed, since
+    /// the value has been delivered either way.
+    pub fn state(&self) -> FutureState {
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        match *state {
+            State::Pending => FutureState::Pending,
+            State::Set(_) | State::Claimed => FutureState::Ready,
+            State::Cancelled(_) | State::Panicked(_) => FutureState::Cancelled,
+        }
+    }
+    /// Whether a result has already been set or not
+    pub fn is_set(&self) -> bool {
+        self.state() == FutureState::Ready
+    }
     /// Cancels the future
     pub fn cancel(&self) {
-        // Cancel the future and wake waiting threads
-        self.cancelled.store(true, SeqCst);
-        self.future.signal.notify_all();
+        self.try_cancel();
+    }
+    /// Blocks until the future has been cancelled, or returns immediately if it has been cancelled already
+    ///
+    /// # Note
+    /// This allows a supervision thread to park on the cancellation instead of polling [`Setter::is_cancelled`]. Both
+    /// explicit getter-side cancellation and dropping the getter wake the blocked thread. If another setter sets the
+    /// result in the meantime, this function returns too.
+    pub fn wait_cancelled(&self) {
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let _state = self.future.signal.wait_while(state, |state| state.is_pending()).expect("The future is poisoned?!");
+    }
+    /// Blocks until the future has been cancelled or the timeout is reached, returns whether the future has been
+    /// cancelled
+    ///
+    /// # Note
+    /// The wait returns as soon as the cancellation arrives instead of sleeping out the full timeout. If another setter
+    /// sets the result in the meantime, this function returns early with `false`, since the future has not been
+    /// cancelled.
+    pub fn wait_cancelled_timeout(&self, timeout: Duration) -> bool {
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let (state, _) = (self.future.signal)
+            .wait_timeout_while(state, timeout, |state| state.is_pending())
+            .expect("The future is poisoned?!");
+        matches!(*state, State::Cancelled(_) | State::Panicked(_))
+    }
+    /// Stores `waker` in `slot` to be woken once the future is no longer pending, returns whether the future is no
+    /// longer pending already, see [`Setter::cancelled`]
+    ///
+    /// # Note
+    /// The waker is registered before the state is checked, so a concurrent cancellation can never be missed. Each slot
+    /// holds only the most recently registered waker, and the future only keeps track of slots that are still alive.
+    #[cfg(feature = "async")]
+    pub(in crate) fn poll_cancelled(&self, slot: &mut Option<Arc<WakerSlot>>, waker: &Waker) -> bool {
+        match slot {
+            Some(slot) => {
+                // Replace the waker unless it would wake the same task anyway
+                let mut registered = slot.lock().expect("The future is poisoned?!");
+                if !registered.as_ref().is_some_and(|registered| registered.will_wake(waker)) {
+                    *registered = Some(waker.clone());
+                }
+            }
+            None => {
+                // Register a new slot and prune the slots of dropped futures
+                let new = Arc::new(Mutex::new(Some(waker.clone())));
+                let mut slots = self.future.cancel_wakers.lock().expect("The future is poisoned?!");
+                slots.retain(|slot| slot.strong_count() > 0);
+                slots.push(Arc::downgrade(&new));
+                *slot = Some(new);
+            }
+        }
+
+        // Check the state after releasing the waker locks
+        !self.future.state.lock().expect("The future is poisoned?!").is_pending()
+    }
+    /// Cancels the future and reports whether it has been cancelled by this call, or whether a result has already been
+    /// set or the future has already been cancelled
+    ///
+    /// # Note
+    /// The outcome is determined atomically with respect to setting the result, so [`CancelOutcome::Cancelled`]
+    /// guarantees that no result has been or will be delivered.
+    pub fn try_cancel(&self) -> CancelOutcome {
+        // Discard the cancellation hooks since they only apply to getter-side cancellations
+        match self.future.cancel(&self.cancelled, Cancelled::Explicit) {
+            Ok(_) => CancelOutcome::Cancelled,
+            Err(outcome) => outcome,
+        }
+    }
+
+    /// When the future has been completed or cancelled
+    #[cfg(feature = "timing")]
+    pub fn completed_at(&self) -> Option<Instant> {
+        self.future.timing.completed_at()
+    }
+    /// The time between the creation and the completion or cancellation of the future
+    #[cfg(feature = "timing")]
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.future.timing.elapsed()
+    }
+
+    /// Announces an upper bound for when the result will be available, see [`Getter::deadline`]
+    ///
+    /// # Note
+    /// The deadline is purely informational and replaces a previously announced deadline; the setter can still set the
+    /// result after the deadline has passed.
+    pub fn set_deadline(&self, deadline: Instant) {
+        *self.future.deadline.lock().expect("The future is poisoned?!") = Some(deadline);
+    }
+
+    /// Registers a callback that is invoked exactly once if the getter cancels the future (explicitly or by being
+    /// dropped), or immediately if the future has already been cancelled
+    ///
+    /// # Note
+    /// The callback runs on the thread that cancels the future, which allows e.g. kicking a producer that is blocked in
+    /// third-party code out of a blocking read. If the future completes instead, the callback is dropped without being
+    /// invoked.
+    pub fn on_cancelled<F>(&self, callback: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // Check the state under the lock so that a concurrent cancellation either observes the registered callback or
+        // is observed here
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        match *state {
+            State::Pending => self.future.cancel_hooks.lock().expect("The future is poisoned?!").push(Box::new(callback)),
+            State::Cancelled(_) | State::Panicked(_) => {
+                drop(state);
+                callback();
+            }
+            State::Set(_) | State::Claimed => (),
+        }
+    }
+    /// Completes this future with the result of `source`, and cancels `source` if this future is cancelled
+    ///
+    /// # Note
+    /// No thread is blocked: the result is forwarded on the thread that completes `source`. If `source` is cancelled,
+    /// this future is cancelled too; if the getter of this future is dropped or cancelled, `source` is cancelled.
+    pub fn forward(self, source: Getter<T>)
+    where
+        T: Send + 'static,
+    {
+        // Propagate cancellation back to the source and the result forward
+        self.on_cancelled(source.canceller());
+        source.on_complete(move |result| match result {
+            Some(value) => self.set(value),
+            None => drop(self),
+        });
+    }
+
+    /// Computes and sets the result, and delivers the panic payload to the getter if `f` panics
+    ///
+    /// # Note
+    /// The closure runs outside of the lock, so a panic does not poison the future. The payload can be claimed via
+    /// [`Getter::wait_outcome`]; all other operations treat the future as cancelled.
+    pub fn complete_with<F>(self, f: F)
+    where
+        F: FnOnce() -> T,
+    {
+        match panic::catch_unwind(AssertUnwindSafe(f)) {
+            Ok(value) => self.set(value),
+            Err(payload) => {
+                // Discard the cancellation hooks since the future cannot be cancelled anymore
+                let _ = self.future.terminate(&self.cancelled, State::Panicked(payload));
+            }
+        }
+    }
+    /// Creates a type-erased handle to report the progress of the future, see [`crate::with_progress`]
+    pub(in crate) fn reporter(&self) -> Arc<dyn Report>
+    where
+        T: Send + 'static,
+    {
+        self.future.clone()
+    }
+    /// Defers the computation of the result until the getter waits for it for the first time, see [`crate::lazy`]
+    pub(in crate) fn defer<F>(self, f: F)
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let future = self.future.clone();
+        let deferred: Deferred = Box::new(move || self.complete_with(f));
+        *future.deferred.lock().expect("The future is poisoned?!") = Some(deferred);
+    }
+    /// Configures a fallback result that is set instead of cancelling the future if the last setter is dropped without
+    /// setting a result
+    ///
+    /// # Note
+    /// The fallback neither overrides an explicit set nor an explicit cancellation, but it is delivered if the setter is
+    /// dropped while unwinding from a panic. Configuring another fallback replaces the previous one.
+    pub fn on_drop_complete_with(&self, fallback: T) {
+        *self.future.fallback.lock().expect("The future is poisoned?!") = Some(fallback);
+    }
+    /// Configures the default value as fallback result, see [`Setter::on_drop_complete_with`]
+    pub fn on_drop_complete_with_default(&self)
+    where
+        T: Default,
+    {
+        self.on_drop_complete_with(T::default());
+    }
+    /// Converts the setter into an opaque pointer, e.g. to pass it as `user_data` through a C callback
+    ///
+    /// # Note
+    /// The setter is leaked until it is reconstituted via [`Setter::from_raw`]; until then, it counts as a live setter
+    /// and the future is not cancelled.
+    ///
+    /// # Example
+    /// ```rust
+    /// let (setter, getter) = tiny_future::new::<u8>();
+    ///
+    /// // Pass the setter through a `void *user_data` and reconstitute it in the callback
+    /// let user_data = setter.into_raw();
+    /// let setter = unsafe { tiny_future::Setter::<u8>::from_raw(user_data) };
+    /// setter.set(7);
+    /// assert_eq!(getter.wait(), Some(7));
+    /// ```
+    pub fn into_raw(self) -> *mut c_void {
+        Box::into_raw(Box::new(self)).cast()
+    }
+    /// Reconstitutes a setter from a pointer that has been created via [`Setter::into_raw`]
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by [`Setter::into_raw`] for the same `T`, and must be reconstituted at most once.
+    /// The reconstituted setter behaves exactly like the original one, i.e. dropping it cancels the future.
+    pub unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        // SAFETY: The caller guarantees that `ptr` has been created via `Box::into_raw` for a setter of the same type and
+        // that it is reconstituted only once
+        *Box::from_raw(ptr.cast())
+    }
+    /// Creates a weak handle that does not keep the future from being cancelled, see [`WeakSetter::upgrade`]
+    pub fn downgrade(&self) -> WeakSetter<T> {
+        WeakSetter { future: Arc::downgrade(&self.future), cancelled: self.cancelled.clone() }
+    }
+    /// Consumes the setter without cancelling the future, so that the future stays pending until the getter cancels it
+    ///
+    /// # Note
+    /// If no other setter exists, the future can never complete, so an unbounded [`Getter::wait`] blocks forever; use a
+    /// bounded wait like [`Getter::wait_timeout`] or [`Getter::wait_checked`] instead. An abandoned setter does not count
+    /// as live, so careful callers can detect it via [`Getter::has_setter`].
+    pub fn abandon(mut self) {
+        self.abandoned = true;
     }
 
     /// Sets the result
+    ///
+    /// # Note
+    /// If the future has been cancelled, the value is dropped silently; use [`Setter::try_set`] to recover it instead.
     pub fn set(self, value: T) {
-        // Only do something if the future has not been cancelled
-        if !self.is_cancelled() {
-            // Set result and wake waiting threads
-            let mut result = self.future.result.lock().expect("The future is poisoned?!");
-            *result = Some(value);
-            self.future.signal.notify_all();
+        let _ = self.try_set(value);
+    }
+    /// Sets the result only if a thread is currently blocked waiting on the future, or hands both the setter and the
+    /// value back otherwise
+    ///
+    /// # Note
+    /// Waiting threads register themselves under the lock of the future, so a consumer that starts waiting concurrently
+    /// either receives the value or finds the future still pending. If the future has been cancelled, the value is
+    /// dropped.
+    pub fn set_if_waiting(self, value: T) -> Result<(), (Self, T)> {
+        // Check for a waiting thread under the lock
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        if state.is_pending() && self.future.waiters.load(SeqCst) == 0 {
+            drop(state);
+            return Err((self, value));
         }
+
+        // Set the result
+        let result = self.future.set_locked(state, value);
+        drop(result);
+        Ok(())
+    }
+    /// Computes and sets the result, but only invokes `f` if the future has not been cancelled
+    ///
+    /// # Note
+    /// The value is computed outside of the lock, so the future may be cancelled while `f` is running. Cancellation is
+    /// therefore checked again before the value is stored; if the cancellation has won the race, the computed value is
+    /// dropped before this function returns.
+    pub fn set_with<F>(self, f: F)
+    where
+        F: FnOnce() -> T,
+    {
+        // Skip the computation if the future is not pending anymore
+        let is_pending = self.future.state.lock().expect("The future is poisoned?!").is_pending();
+        if is_pending {
+            // Compute the value outside of the lock and drop it if the future has been cancelled in the meantime
+            let result = self.try_set(f());
+            drop(result);
+        }
+    }
+    /// Sets the result, or hands the value back if the future has been cancelled or has already been set by another
+    /// setter
+    ///
+    /// # Note
+    /// The outcome is decided under the lock of the future, so a concurrent cancellation or a concurrent set by another
+    /// setter either observes the delivered value or causes the value to be handed back, but never both.
+    pub fn try_set(self, value: T) -> Result<(), SetError<T>> {
+        self.future.set(value)
     }
 }
 impl<T> Debug for Setter<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Setter").field("future", &self.future).field("cancelled", &self.cancelled.load(SeqCst)).finish()
+        f.debug_struct("Setter")
+            .field("id", &self.id())
+            .field("future", &self.future)
+            .field("cancelled", &self.cancelled.load(SeqCst))
+            .field("abandoned", &self.abandoned)
+            .finish()
+    }
+}
+impl<T> Clone for Setter<T> {
+    /// Creates another setter for the same future
+    ///
+    /// # Note
+    /// The first setter to set a result wins; subsequent sets are rejected. The future is only cancelled implicitly if
+    /// the last setter is dropped without a result having been set.
+    fn clone(&self) -> Self {
+        Self::new(self.future.clone(), self.cancelled.clone())
     }
 }
 impl<T> Drop for Setter<T> {
     fn drop(&mut self) {
-        self.cancel();
+        // Unregister the setter before waking waiting threads; only the last setter cancels the future unless it has been
+        // abandoned, and records that the setter has been dropped if the future is still pending
+        let is_last = self.future.setters.fetch_sub(1, SeqCst) == 1;
+        if is_last && !self.abandoned {
+            // Deliver the fallback result if any; this does not override a previous set or cancellation
+            let fallback = self.future.fallback.lock().expect("The future is poisoned?!").take();
+            if let Some(fallback) = fallback {
+                let _ = self.future.set(fallback);
+            }
+            let _ = self.future.cancel(&self.cancelled, Cancelled::SetterDropped);
+        } else if is_last {
+            // Wake threads that wait for a live setter under the lock, so that they cannot miss the wakeup
+            drop(self.future.state.lock().expect("The future is poisoned?!"));
+            self.future.signal.notify_all();
+        }
+        self.future.release(&self.cancelled);
+    }
+}
+
+/// A weak handle to a setter that does not keep the future from being cancelled, see [`Setter::downgrade`]
+///
+/// # Note
+/// If only weak handles remain, the future behaves as if the setter has been dropped.
+pub struct WeakSetter<T> {
+    /// The underlying future
+    future: Weak<Future<T>>,
+    /// Whether the future has been cancelled or not
+    cancelled: Arc<AtomicBool>,
+}
+impl<T> WeakSetter<T> {
+    /// Upgrades the handle to a setter, returns `None` if no setter is live anymore
+    ///
+    /// # Note
+    /// Once the last setter has been dropped, upgrading is impossible even if the future is still referenced elsewhere.
+    pub fn upgrade(&self) -> Option<Setter<T>> {
+        // Register a new setter only if another setter is still live
+        let future = self.future.upgrade()?;
+        let register = |setters: usize| setters.checked_add(1).filter(|_| setters > 0);
+        future.setters.fetch_update(SeqCst, SeqCst, register).ok()?;
+        future.handles.fetch_add(1, SeqCst);
+        Some(Setter { future, cancelled: self.cancelled.clone(), abandoned: false })
+    }
+}
+impl<T> Clone for WeakSetter<T> {
+    fn clone(&self) -> Self {
+        Self { future: self.future.clone(), cancelled: self.cancelled.clone() }
+    }
+}
+impl<T> Debug for WeakSetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakSetter").field("future", &self.future).field("cancelled", &self.cancelled.load(SeqCst)).finish()
+    }
+}
+
+/// A weak handle to cancel a future from the getter side without keeping it alive, see [`Getter::weak_canceller`]
+struct WeakCanceller<T> {
+    /// The underlying future
+    future: Weak<Future<T>>,
+    /// The cancellation flag
+    cancelled: Arc<AtomicBool>,
+}
+impl<T> Cancel for WeakCanceller<T>
+where
+    T: Send,
+{
+    fn cancel(&self) {
+        if let Some(future) = self.future.upgrade() {
+            future.cancel_getter(&self.cancelled);
+        }
+    }
+    fn is_resolved(&self) -> bool {
+        self.cancelled.load(SeqCst) || self.future.strong_count() == 0
     }
 }
 
@@ -91,53 +953,686 @@ pub struct Getter<T> {
 }
 impl<T> Getter<T> {
     /// Creates a new getter
-    pub(in crate) const fn new(future: Arc<Future<T>>, cancelled: Arc<AtomicBool>) -> Self {
+    pub(in crate) fn new(future: Arc<Future<T>>, cancelled: Arc<AtomicBool>) -> Self {
+        // Register the getter as live
+        future.handles.fetch_add(1, SeqCst);
         Self { future, cancelled }
     }
 
+    /// The identity token that is shared with the corresponding setter
+    pub fn id(&self) -> FutureId {
+        self.future.id
+    }
+    /// Whether a setter that could still complete the future exists or not
+    ///
+    /// # Note
+    /// A setter that has been leaked (e.g. via [`std::mem::forget`]) remains live forever, since there is no way to tell
+    /// it apart from a setter that is merely slow.
+    pub fn has_setter(&self) -> bool {
+        self.future.setters.load(SeqCst) > 0
+    }
     /// Whether the future has been cancelled or not
     pub fn is_cancelled(&self) -> bool {
         self.cancelled.load(SeqCst)
     }
     /// Cancels the future
     pub fn cancel(&self) {
-        self.cancelled.store(true, SeqCst);
+        self.try_cancel();
+    }
+    /// Cancels the future and reports whether it has been cancelled by this call, see [`Setter::try_cancel`]
+    pub fn try_cancel(&self) -> CancelOutcome {
+        self.future.cancel_getter(&self.cancelled)
+    }
+
+    /// When the future has been completed or cancelled
+    #[cfg(feature = "timing")]
+    pub fn completed_at(&self) -> Option<Instant> {
+        self.future.timing.completed_at()
+    }
+    /// The time between the creation and the completion or cancellation of the future
+    #[cfg(feature = "timing")]
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.future.timing.elapsed()
+    }
+
+    /// The human-readable name of the producer if any, e.g. the name of the thread that computes the result for
+    /// [`crate::spawn_with`]
+    pub fn label(&self) -> Option<&str> {
+        self.future.label.get().map(String::as_str)
+    }
+    /// Registers `waker` to be woken once the future is resolved and claims the result if the future is resolved already,
+    /// returns either `Some(Ok(result))` if the future has completed successfully, `Some(Err(reason))` if the future has
+    /// been cancelled, or `None` if the future is still pending
+    ///
+    /// # Note
+    /// The waker is registered before the state is checked, so a concurrent resolution can never be missed. Only the
+    /// most recently registered waker is woken, so the getter may be moved between tasks while it is pending.
+    #[cfg(feature = "async")]
+    pub(in crate) fn poll_ready(&mut self, waker: &Waker) -> Option<Result<T, Cancelled>> {
+        // Replace the registered waker unless it would wake the same task anyway
+        let mut registered = self.future.waker.lock().expect("The future is poisoned?!");
+        if !registered.as_ref().is_some_and(|registered| registered.will_wake(waker)) {
+            *registered = Some(waker.clone());
+        }
+
+        // Evaluate a deferred computation and claim the result if the future is resolved
+        drop(registered);
+        self.future.evaluate();
+        self.take_result_if_ready()
+    }
+    /// A file descriptor that becomes readable once the future has been completed or cancelled, e.g. to register the
+    /// future with `epoll` or `poll`
+    ///
+    /// # Note
+    /// The descriptor is created on first use and owned by the future; it stays readable once it has been signalled, so
+    /// [`Getter::try_get`] should be called once it fires. Don't read from or close the descriptor.
+    ///
+    /// # Panics
+    /// This function panics if the descriptor cannot be created.
+    #[cfg(all(unix, feature = "event-fd"))]
+    pub fn as_event_fd(&self) -> BorrowedFd<'_> {
+        let mut created = false;
+        let event_fd = self.future.event_fd.get_or_init(|| {
+            created = true;
+            EventFd::new().expect("Failed to create the event fd?!")
+        });
+
+        // Signal a new descriptor if the future has been resolved before the descriptor existed
+        if created && !self.future.state.lock().expect("The future is poisoned?!").is_pending() {
+            event_fd.signal();
+        }
+        event_fd.as_fd()
+    }
+    /// A manual-reset Win32 event that is set once the future has been completed or cancelled, e.g. to wait for the
+    /// future via `WaitForMultipleObjects`
+    ///
+    /// # Note
+    /// The event is created on first use and owned by the future, so the handle is only borrowed and stays valid while
+    /// the getter is alive. It stays set once it has been signalled, so [`Getter::try_get`] should be called once it
+    /// fires. Don't reset or close the handle.
+    ///
+    /// # Panics
+    /// This function panics if the event cannot be created.
+    #[cfg(all(windows, feature = "windows"))]
+    pub fn as_win32_event(&self) -> RawHandle {
+        let mut created = false;
+        let win32_event = self.future.win32_event.get_or_init(|| {
+            created = true;
+            Win32Event::new().expect("Failed to create the Win32 event?!")
+        });
+
+        // Signal a new event if the future has been resolved before the event existed
+        if created && !self.future.state.lock().expect("The future is poisoned?!").is_pending() {
+            win32_event.signal();
+        }
+        win32_event.as_raw_handle()
+    }
+    /// Reports the getter if it is dropped without ever having been consumed, see [`crate::set_unconsumed_hook`]
+    pub(in crate) fn set_must_consume(&self) {
+        self.future.must_consume.store(true, SeqCst);
+    }
+    /// Labels the future with a human-readable name of the producer, see [`Getter::label`]
+    pub(in crate) fn set_label(&self, label: String) {
+        let _ = self.future.label.set(label);
+    }
+    /// The deadline announced by the setter if any, see [`Setter::set_deadline`]
+    pub fn deadline(&self) -> Option<Instant> {
+        *self.future.deadline.lock().expect("The future is poisoned?!")
+    }
+
+    /// Converts the getter into an opaque pointer, see [`Setter::into_raw`]
+    ///
+    /// # Note
+    /// The getter is leaked until it is reconstituted via [`Getter::from_raw`]; until then, the future is not cancelled.
+    pub fn into_raw(self) -> *mut c_void {
+        Box::into_raw(Box::new(self)).cast()
+    }
+    /// Reconstitutes a getter from a pointer that has been created via [`Getter::into_raw`]
+    ///
+    /// # Safety
+    /// `ptr` must have been returned by [`Getter::into_raw`] for the same `T`, and must be reconstituted at most once.
+    /// The reconstituted getter behaves exactly like the original one, i.e. dropping it cancels the future.
+    pub unsafe fn from_raw(ptr: *mut c_void) -> Self {
+        // SAFETY: The caller guarantees that `ptr` has been created via `Box::into_raw` for a getter of the same type and
+        // that it is reconstituted only once
+        *Box::from_raw(ptr.cast())
+    }
+
+    /// Whether the future has a deferred computation that has not been evaluated yet, see [`crate::lazy`]
+    pub(in crate) fn is_deferred(&self) -> bool {
+        self.future.deferred.lock().expect("The future is poisoned?!").is_some()
+    }
+    /// Registers a shared signal that is notified if the future is resolved
+    ///
+    /// # Note
+    /// A deferred computation is evaluated on the current thread once the signal has been registered, see
+    /// [`crate::lazy`].
+    pub(in crate) fn watch(&self, signal: &Arc<Signal>) {
+        let mut watchers = self.future.watchers.lock().expect("The future is poisoned?!");
+        watchers.push(signal.clone());
+        drop(watchers);
+        self.future.run_deferred();
+    }
+    /// Unregisters a shared signal that has been registered via [`Getter::watch`]
+    pub(in crate) fn unwatch(&self, signal: &Arc<Signal>) {
+        let mut watchers = self.future.watchers.lock().expect("The future is poisoned?!");
+        watchers.retain(|watcher| !Arc::ptr_eq(watcher, signal));
+    }
+    /// Schedules a cancellation of the future after `delay` unless the future has completed by then
+    ///
+    /// # Note
+    /// The cancellation is executed on a shared timer thread; it wakes threads that are blocked waiting on the future.
+    /// The returned handle can be used to abort the scheduled cancellation.
+    pub fn cancel_after(&self, delay: Duration) -> ScheduledCancel
+    where
+        T: Send + 'static,
+    {
+        let (future, cancelled) = (self.future.clone(), self.cancelled.clone());
+        let entry = TimerEntry::after(delay, move || {
+            future.cancel_getter(&cancelled);
+        });
+        ScheduledCancel::new(entry)
+    }
+    /// Creates a type-erased handle that cancels the future as if [`Getter::cancel`] was called
+    pub(in crate) fn canceller(&self) -> impl FnOnce() + Send + 'static
+    where
+        T: Send + 'static,
+    {
+        let (future, cancelled) = (self.future.clone(), self.cancelled.clone());
+        move || {
+            future.cancel_getter(&cancelled);
+        }
+    }
+
+    /// Creates a type-erased handle that cancels the future as if [`Getter::cancel`] was called, without keeping the
+    /// future alive
+    pub(in crate) fn weak_canceller(&self) -> Box<dyn Cancel>
+    where
+        T: Send + 'static,
+    {
+        Box::new(WeakCanceller { future: Arc::downgrade(&self.future), cancelled: self.cancelled.clone() })
+    }
+
+    /// The current state of the future
+    pub fn state(&self) -> FutureState {
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        match *state {
+            State::Pending => FutureState::Pending,
+            State::Set(_) => FutureState::Ready,
+            State::Claimed | State::Cancelled(_) | State::Panicked(_) => FutureState::Cancelled,
+        }
+    }
+    /// Whether a result is available or not
+    pub fn is_ready(&self) -> bool {
+        self.state() == FutureState::Ready
     }
 
     /// Waits until the result is ready, returns either `Some(result)` if the future has completed successfully or `None`
     /// if the future has been cancelled
     pub fn wait(self) -> Option<T> {
+        self.wait_result().ok()
+    }
+    /// Busy-polls the future for up to `spin` before falling back to a blocking [`Getter::wait`], returns either
+    /// `Some(result)` if the future has completed successfully or `None` if the future has been cancelled
+    ///
+    /// # Note
+    /// This avoids the wakeup latency of the blocking wait if the result is expected to arrive within a few microseconds,
+    /// at the cost of burning CPU during the spin phase. Keep `spin` short.
+    pub fn wait_spin(self, spin: Duration) -> Option<T> {
+        // Evaluate a deferred computation first, since spinning would never resolve it
+        self.future.evaluate();
+
+        // Spin until the future is resolved or the spin period is over
+        let start = Instant::now();
+        while start.elapsed() < spin {
+            // Check the state without blocking
+            let is_pending = self.future.state.try_lock().map(|state| state.is_pending()).unwrap_or(true);
+            if !is_pending || self.is_cancelled() {
+                break;
+            }
+            hint::spin_loop();
+        }
+
+        // Wait for the future or claim the result
+        self.wait()
+    }
+    /// Waits until the result is ready, returns either `Ok(result)` if the future has completed successfully or an error
+    /// describing why the future has been cancelled
+    pub fn wait_result(self) -> Result<T, Cancelled> {
         // Wait for the future if necessary
-        let cond = |result: &mut Option<T>| result.is_none() && !self.is_cancelled();
-        let result = self.future.result.lock().expect("The future is poisoned?!");
-        let mut result = self.future.signal.wait_while(result, cond).expect("The future is poisoned?!");
+        self.future.evaluate();
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let waiter = WaiterGuard::new(&self.future.waiters);
+        let mut state = self.future.signal.wait_while(state, |state| state.is_pending()).expect("The future is poisoned?!");
+        drop(waiter);
 
         // Claim the result
-        result.take()
+        let result = Future::claim(&mut state, &self.cancelled).expect("The future is still pending?!");
+        drop(state);
+        self.future.inspect_claimed(result.as_ref().ok());
+        result
+    }
+    /// Waits until the result is ready or no setter is left to complete the future, returns either `Ok(result)` if the
+    /// future has completed successfully or an error describing why the future cannot complete
+    ///
+    /// # Note
+    /// Unlike [`Getter::wait_result`], this returns [`Cancelled::NoSetter`] instead of blocking forever once every setter
+    /// has been abandoned via [`Setter::abandon`]. A leaked setter still counts as live, see [`Getter::has_setter`].
+    pub fn wait_checked(self) -> Result<T, Cancelled> {
+        // Wait while the future is pending and a setter could still complete it
+        self.future.evaluate();
+        let is_completable = |state: &mut State<T>| state.is_pending() && self.future.setters.load(SeqCst) > 0;
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let waiter = WaiterGuard::new(&self.future.waiters);
+        let mut state = self.future.signal.wait_while(state, is_completable).expect("The future is poisoned?!");
+        drop(waiter);
+
+        // Claim the result, or report that the future can never complete
+        let Some(result) = Future::claim(&mut state, &self.cancelled) else {
+            return Err(Cancelled::NoSetter);
+        };
+        drop(state);
+        self.future.inspect_claimed(result.as_ref().ok());
+        result
+    }
+    /// Waits until the result is ready, returns either the result if the future has completed successfully or `fallback`
+    /// if the future has been cancelled
+    pub fn wait_or(self, fallback: T) -> T {
+        self.wait().unwrap_or(fallback)
+    }
+    /// Waits until the result is ready, returns either the result if the future has completed successfully or the result
+    /// of `fallback` if the future has been cancelled
+    pub fn wait_or_else<F>(self, fallback: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        self.wait().unwrap_or_else(fallback)
+    }
+    /// Waits until the result is ready, returns either the result if the future has completed successfully or the
+    /// default value if the future has been cancelled
+    pub fn wait_or_default(self) -> T
+    where
+        T: Default,
+    {
+        self.wait().unwrap_or_default()
+    }
+    /// Waits until the result is ready without claiming it, returns either a guard that borrows the result if the future
+    /// has completed successfully or `None` if the future has been cancelled
+    ///
+    /// # Note
+    /// The result stays in place, so it can be borrowed again or claimed via [`Getter::wait`] afterwards.
+    pub fn wait_ref(&self) -> Option<ValueGuard<'_, T>> {
+        // Wait for the future if necessary
+        self.future.evaluate();
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let waiter = WaiterGuard::new(&self.future.waiters);
+        let state = self.future.signal.wait_while(state, |state| state.is_pending()).expect("The future is poisoned?!");
+        drop(waiter);
+
+        // Borrow the result
+        match *state {
+            State::Set(_) => Some(ValueGuard { state }),
+            _ => None,
+        }
     }
     /// Waits until a result is available or the timeout is reached
     pub fn wait_timeout(self, timeout: Duration) -> Result<Option<T>, Self> {
-        // Wait while the queue is empty and not cancelled and the timeout is not reached
-        let cond = |queue: &mut Option<T>| queue.is_none() && !self.is_cancelled();
-        let result = self.future.result.lock().expect("The future is poisoned?!");
-        let (mut result, timeout_result) =
-            self.future.signal.wait_timeout_while(result, timeout, cond).expect("The future is poisoned?!");
+        self.wait_timeout_result(timeout).map(Result::ok)
+    }
+    /// Waits until a result is available or the timeout is reached, returns either `Ok(result)` if the future has been
+    /// resolved in time, or the getter if the timeout has been reached
+    pub(in crate) fn wait_timeout_result(self, timeout: Duration) -> Result<Result<T, Cancelled>, Self> {
+        // Wait while the future is pending and the timeout is not reached
+        self.future.evaluate();
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let waiter = WaiterGuard::new(&self.future.waiters);
+        let (mut state, _) = (self.future.signal)
+            .wait_timeout_while(state, timeout, |state| state.is_pending())
+            .expect("The future is poisoned?!");
+        drop(waiter);
 
-        // Claim the result
-        if timeout_result.timed_out() {
-            drop(result);
+        // Claim the result if the future has been resolved in time
+        let Some(result) = Future::claim(&mut state, &self.cancelled) else {
+            drop(state);
             return Err(self);
+        };
+        drop(state);
+        self.future.inspect_claimed(result.as_ref().ok());
+        Ok(result)
+    }
+    /// Waits until a result is available or the timeout is reached, and reports the unused time budget on success
+    ///
+    /// # Note
+    /// The remaining time is measured against a monotonic clock and saturates at zero if the result arrives right at the
+    /// timeout boundary.
+    pub fn wait_timeout_remaining(self, timeout: Duration) -> Result<(Option<T>, Duration), Self> {
+        let start = Instant::now();
+        let result = self.wait_timeout(timeout)?;
+        Ok((result, timeout.saturating_sub(start.elapsed())))
+    }
+    /// Waits until the result is ready, and reports the outcome as an exhaustively matchable [`JoinOutcome`]
+    ///
+    /// # Note
+    /// Unlike [`Getter::wait`], this reports a panic of the setter via [`JoinOutcome::Panicked`], see
+    /// [`Setter::complete_with`].
+    pub fn wait_outcome(self) -> JoinOutcome<T> {
+        // Wait while the future is pending
+        self.future.evaluate();
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let waiter = WaiterGuard::new(&self.future.waiters);
+        let mut state =
+            self.future.signal.wait_while(state, |state| state.is_pending()).expect("The future is poisoned?!");
+        drop(waiter);
+
+        // Claim the panic payload or the result
+        if let Some(payload) = Future::claim_panic(&mut state) {
+            return JoinOutcome::Panicked(payload);
+        }
+        let result = Future::claim(&mut state, &self.cancelled);
+        drop(state);
+        match result {
+            Some(Ok(value)) => {
+                self.future.inspect_claimed(Some(&value));
+                JoinOutcome::Ready(value)
+            }
+            Some(Err(_)) => JoinOutcome::Cancelled,
+            None => unreachable!("The future is still pending?!"),
+        }
+    }
+    /// Waits until a result is available or the timeout is reached, and reports the outcome as an exhaustively
+    /// matchable [`WaitOutcome`]
+    pub fn wait_timeout_outcome(self, timeout: Duration) -> WaitOutcome<T> {
+        match self.wait_timeout(timeout) {
+            Ok(Some(value)) => WaitOutcome::Ready(value),
+            Ok(None) => WaitOutcome::Cancelled,
+            Err(getter) => WaitOutcome::TimedOut(getter),
+        }
+    }
+    /// Consumes the getter without cancelling the future
+    ///
+    /// # Note
+    /// This allows fire-and-forget usage: the setter does not observe a cancellation and can still call
+    /// [`Setter::set`], but the result is dropped immediately since nobody can claim it anymore.
+    pub fn detach(self) {
+        // Mark the getter as detached and drop a result that might have been set already
+        let mut state = self.future.state.lock().expect("The future is poisoned?!");
+        self.future.detached.store(true, SeqCst);
+        let result = Future::claim(&mut state, &self.cancelled);
+        drop((state, result));
+
+        // Discard a deferred computation since nobody will ever wait for it
+        let deferred = self.future.deferred.lock().expect("The future is poisoned?!").take();
+        drop(deferred);
+    }
+    /// Registers a hook that is invoked exactly once with a reference to the result once it is claimed
+    ///
+    /// # Note
+    /// The hook runs on the thread that claims the result (e.g. via [`Getter::wait`] or [`Getter::try_get`]), after the
+    /// result has been taken out of the future but before it is returned. It does not run if the future is cancelled.
+    /// Stacked hooks run in registration order.
+    pub fn inspect<F>(self, inspector: F) -> Self
+    where
+        F: FnOnce(&T) + Send + 'static,
+    {
+        let mut inspectors = self.future.inspectors.lock().expect("The future is poisoned?!");
+        inspectors.push(Box::new(inspector));
+        drop(inspectors);
+        self
+    }
+    /// Registers a callback that is invoked exactly once with either `Some(result)` if the future has completed
+    /// successfully or `None` if the future has been cancelled
+    ///
+    /// # Note
+    /// If the future is already resolved, the callback is invoked immediately on the current thread; otherwise it is
+    /// invoked on the thread that sets or cancels the future. Registering a callback consumes the getter without
+    /// cancelling the future.
+    pub fn on_complete<F>(self, callback: F)
+    where
+        F: FnOnce(Option<T>) + Send + 'static,
+    {
+        // Evaluate a deferred computation, mark the getter as detached so that dropping it does not cancel the future,
+        // and claim the result if any
+        self.future.run_deferred();
+        let mut state = self.future.state.lock().expect("The future is poisoned?!");
+        self.future.detached.store(true, SeqCst);
+        let result = Future::claim(&mut state, &self.cancelled);
+
+        // Either invoke the callback immediately if the future is resolved or register it
+        match result {
+            Some(Ok(value)) => {
+                drop(state);
+                self.future.inspect_claimed(Some(&value));
+                callback(Some(value));
+            }
+            Some(Err(_)) => {
+                drop(state);
+                callback(None);
+            }
+            None => *self.future.callback.lock().expect("The future is poisoned?!") = Some(Box::new(callback)),
+        }
+    }
+    /// Waits until the result is ready, the future has been cancelled, or the wait has been interrupted
+    ///
+    /// # Note
+    /// The wait is interrupted if `stop` is set and an [`Interrupter`] for this future is notified; an interruption
+    /// hands the getter back and does not cancel the future, so it remains completable afterwards.
+    pub fn wait_interruptible(self, stop: &AtomicBool) -> InterruptOutcome<T> {
+        // Wait for the future if necessary
+        self.future.evaluate();
+        let cond = |state: &mut State<T>| state.is_pending() && !stop.load(SeqCst);
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let waiter = WaiterGuard::new(&self.future.waiters);
+        let mut state = self.future.signal.wait_while(state, cond).expect("The future is poisoned?!");
+        drop(waiter);
+
+        // Claim the result
+        let result = Future::claim(&mut state, &self.cancelled);
+        drop(state);
+        match result {
+            Some(Ok(value)) => {
+                self.future.inspect_claimed(Some(&value));
+                InterruptOutcome::Ready(value)
+            }
+            Some(Err(_)) => InterruptOutcome::Cancelled,
+            None => InterruptOutcome::Interrupted(self),
         }
-        Ok(result.take())
+    }
+    /// The latest progress reported via [`crate::ProgressSetter::report`] if any
+    ///
+    /// # Note
+    /// This returns `None` if no progress has been reported yet or if the progress is not of type `P`.
+    pub fn progress<P>(&self) -> Option<P>
+    where
+        P: Clone + 'static,
+    {
+        let progress = self.future.progress.lock().expect("The future is poisoned?!");
+        progress.value.as_ref()?.downcast_ref::<P>().cloned()
+    }
+    /// Waits until new progress is reported, the future is resolved, or the timeout is reached, returns the latest
+    /// progress if new progress has been reported, see [`Getter::progress`]
+    ///
+    /// # Note
+    /// This does not consume the result; use e.g. [`Getter::state`] to tell a resolution apart from a timeout. The wait
+    /// is not reported via [`Setter::has_waiter`], since the thread does not wait for the result.
+    pub fn wait_progress_timeout<P>(&self, timeout: Duration) -> Option<P>
+    where
+        P: Clone + 'static,
+    {
+        // Wait while the future is pending and no new progress has been reported
+        let state = self.future.state.lock().expect("The future is poisoned?!");
+        let version = self.future.progress.lock().expect("The future is poisoned?!").version;
+        let is_unchanged = |state: &mut State<T>| {
+            let progress = self.future.progress.lock().expect("The future is poisoned?!");
+            state.is_pending() && progress.version == version
+        };
+        let (state, _) =
+            self.future.signal.wait_timeout_while(state, timeout, is_unchanged).expect("The future is poisoned?!");
+        drop(state);
+
+        // Report the progress if it has changed
+        let progress = self.future.progress.lock().expect("The future is poisoned?!");
+        if progress.version == version {
+            return None;
+        }
+        progress.value.as_ref()?.downcast_ref::<P>().cloned()
+    }
+    /// Creates an interrupter to wake threads that are blocked in [`Getter::wait_interruptible`]
+    pub fn interrupter(&self) -> Interrupter
+    where
+        T: Send + 'static,
+    {
+        Interrupter::new(self.future.clone())
+    }
+    /// Waits until the result is ready while invoking `heartbeat` every `interval`
+    ///
+    /// # Note
+    /// The heartbeat is scheduled against absolute tick deadlines so that it does not drift; ticks that have been missed
+    /// because the heartbeat took too long are skipped. If `heartbeat` returns [`ControlFlow::Break`], the wait is
+    /// aborted and the getter is handed back via [`InterruptOutcome::Interrupted`].
+    pub fn wait_with_heartbeat<F>(self, interval: Duration, mut heartbeat: F) -> InterruptOutcome<T>
+    where
+        F: FnMut() -> ControlFlow<()>,
+    {
+        let mut getter = self;
+        let mut next_tick = Instant::now();
+        loop {
+            // Compute the next tick and skip missed ticks; if it is not representable, it will never be reached
+            let now = Instant::now();
+            let next = next_tick.checked_add(interval).filter(|next| *next >= now).or_else(|| now.checked_add(interval));
+            next_tick = match next {
+                Some(next) => next,
+                None => return getter.wait().map_or(InterruptOutcome::Cancelled, InterruptOutcome::Ready),
+            };
+
+            // Wait until the next tick
+            getter = match getter.wait_deadline(next_tick) {
+                Ok(Some(value)) => return InterruptOutcome::Ready(value),
+                Ok(None) => return InterruptOutcome::Cancelled,
+                Err(getter) => getter,
+            };
+
+            // Invoke the heartbeat
+            if heartbeat().is_break() {
+                return InterruptOutcome::Interrupted(getter);
+            }
+        }
+    }
+    /// Waits until a result is available or the deadline is reached
+    ///
+    /// # Note
+    /// If the deadline lies in the past, this function only checks once whether a result is available.
+    pub fn wait_deadline(self, deadline: Instant) -> Result<Option<T>, Self> {
+        // Compute the remaining time; a deadline in the past results in a zero timeout
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        self.wait_timeout(timeout)
+    }
+    /// Waits until a result is available or the deadline announced by the setter is reached, see
+    /// [`Setter::set_deadline`]
+    ///
+    /// # Note
+    /// The deadline is read once when the wait starts; if no deadline has been announced, this waits indefinitely. If
+    /// the deadline is reached, the getter is handed back via [`DeadlineOutcome::DeadlineExceeded`].
+    pub fn wait_until_deadline(self) -> DeadlineOutcome<T> {
+        // Wait indefinitely if no deadline has been announced
+        let Some(deadline) = self.deadline() else {
+            return self.wait().map_or(DeadlineOutcome::Cancelled, DeadlineOutcome::Ready);
+        };
+        match self.wait_deadline(deadline) {
+            Ok(Some(value)) => DeadlineOutcome::Ready(value),
+            Ok(None) => DeadlineOutcome::Cancelled,
+            Err(getter) => DeadlineOutcome::DeadlineExceeded(getter),
+        }
+    }
+    /// Checks if a result is available without blocking, returns either `Ok(Some(result))` if the future has completed
+    /// successfully, `Ok(None)` if the future has been cancelled, or `Err(self)` if the future is still pending
+    ///
+    /// # Note
+    /// A deferred computation is not evaluated, so a lazy future stays pending until it is waited for, see
+    /// [`crate::lazy`].
+    pub fn try_get(self) -> Result<Option<T>, Self> {
+        self.future.consumed.store(true, SeqCst);
+
+        // Try to claim the result; if the lock is currently held elsewhere, the future is treated as pending
+        let result = match self.future.state.try_lock() {
+            Ok(mut state) => Future::claim(&mut state, &self.cancelled),
+            Err(TryLockError::WouldBlock) => None,
+            Err(TryLockError::Poisoned(_)) => panic!("The future is poisoned?!"),
+        };
+
+        // Check the state of the future
+        match result {
+            Some(Ok(value)) => {
+                self.future.inspect_claimed(Some(&value));
+                Ok(Some(value))
+            }
+            Some(Err(_)) => Ok(None),
+            None => Err(self),
+        }
+    }
+    /// Claims the result if the future is resolved without consuming the getter, returns either `Some(Some(result))` if
+    /// the future has completed successfully, `Some(None)` if the future has been cancelled, or `None` if the future is
+    /// still pending
+    ///
+    /// # Note
+    /// After the result has been claimed, the getter reports the future as cancelled, so that subsequent calls and
+    /// waits return immediately instead of blocking forever.
+    pub fn take_if_ready(&mut self) -> Option<Option<T>> {
+        self.take_result_if_ready().map(Result::ok)
+    }
+    /// Claims the result if the future is resolved without consuming the getter, returns either `Some(Ok(result))` if
+    /// the future has completed successfully, `Some(Err(reason))` if the future has been cancelled, or `None` if the
+    /// future is still pending, see [`Getter::take_if_ready`]
+    fn take_result_if_ready(&mut self) -> Option<Result<T, Cancelled>> {
+        self.future.consumed.store(true, SeqCst);
+
+        // Claim the result if the future is resolved
+        let mut state = self.future.state.lock().expect("The future is poisoned?!");
+        let result = Future::claim(&mut state, &self.cancelled)?;
+        drop(state);
+
+        // Run the inspectors if the future has completed successfully
+        if let Ok(value) = &result {
+            self.future.inspect_claimed(Some(value));
+        }
+        Some(result)
     }
 }
 impl<T> Debug for Getter<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Getter").field("future", &self.future).field("cancelled", &self.cancelled.load(SeqCst)).finish()
+        f.debug_struct("Getter")
+            .field("id", &self.id())
+            .field("label", &self.label())
+            .field("future", &self.future)
+            .field("cancelled", &self.cancelled.load(SeqCst))
+            .finish()
+    }
+}
+impl<T> From<T> for Getter<T> {
+    /// Creates a getter that has already completed with `value`, see [`crate::ready`]
+    ///
+    /// # Note
+    /// This also covers futures that carry a `Result`, so both `Ok(value).into()` and `Err(error).into()` work.
+    fn from(value: T) -> Self {
+        crate::ready(value)
     }
 }
 impl<T> Drop for Getter<T> {
     fn drop(&mut self) {
-        self.cancel();
+        // Report the getter if it must be consumed but has neither been waited on, polled, nor detached
+        let is_unconsumed = !self.future.consumed.load(SeqCst) && !self.future.detached.load(SeqCst);
+        if self.future.must_consume.load(SeqCst) && is_unconsumed && !thread::panicking() {
+            consume::report(self.id(), self.label());
+        }
+
+        // Discard the waker since the task that awaits the getter has lost interest
+        #[cfg(feature = "async")]
+        drop(self.future.waker.lock().expect("The future is poisoned?!").take());
+
+        // Only cancel the future if the getter has not been detached
+        if !self.future.detached.load(SeqCst) {
+            self.cancel();
+        }
+        self.future.getter_alive.store(false, SeqCst);
+        self.future.release(&self.cancelled);
     }
 }
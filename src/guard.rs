@@ -0,0 +1,65 @@
+//! Implements a scope guard that completes a future on drop
+
+use crate::future::Setter;
+use std::fmt::{self, Debug, Formatter};
+
+/// A scope guard that completes the future with a fallback result on drop unless it has been completed or defused
+/// before, see [`Setter::guard`]
+///
+/// # Note
+/// The fallback is also delivered if the guard is dropped while unwinding from a panic. If the fallback itself panics,
+/// the panic is caught and delivered to the getter as if [`Setter::complete_with`] was used.
+pub struct SetterGuard<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// The guarded setter and the fallback
+    inner: Option<(Setter<T>, F)>,
+}
+impl<T, F> SetterGuard<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// Completes the future with `value` instead of the fallback
+    pub fn complete(mut self, value: T) {
+        if let Some((setter, _)) = self.inner.take() {
+            setter.set(value);
+        }
+    }
+    /// Defuses the guard without completing the future, and hands the setter back
+    pub fn defuse(mut self) -> Setter<T> {
+        let (setter, _) = self.inner.take().expect("The guard has been consumed already?!");
+        setter
+    }
+}
+impl<T, F> Debug for SetterGuard<T, F>
+where
+    F: FnOnce() -> T,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let setter = self.inner.as_ref().map(|(setter, _)| setter);
+        f.debug_struct("SetterGuard").field("setter", &setter).field("fallback", &"<opaque>").finish()
+    }
+}
+impl<T, F> Drop for SetterGuard<T, F>
+where
+    F: FnOnce() -> T,
+{
+    fn drop(&mut self) {
+        // Complete the future with the fallback; a panicking fallback must not unwind out of the drop
+        if let Some((setter, fallback)) = self.inner.take() {
+            setter.complete_with(fallback);
+        }
+    }
+}
+
+impl<T> Setter<T> {
+    /// Wraps the setter into a scope guard that completes the future with the result of `fallback` on drop, unless
+    /// [`SetterGuard::complete`] or [`SetterGuard::defuse`] is called before
+    pub fn guard<F>(self, fallback: F) -> SetterGuard<T, F>
+    where
+        F: FnOnce() -> T,
+    {
+        SetterGuard { inner: Some((self, fallback)) }
+    }
+}
@@ -0,0 +1,53 @@
+//! Implements traits that abstract over threaded and single-threaded futures
+
+use crate::{
+    error::SetError,
+    future::{Getter, Setter},
+};
+
+/// The setter side of a future, implemented by [`Setter`] and [`crate::local::LocalSetter`]
+pub trait FutureSetter<T>: Sized {
+    /// Whether the future has been cancelled or not
+    fn is_cancelled(&self) -> bool;
+    /// Sets the result, or hands the value back if the future has been cancelled or has already been set
+    fn try_set(self, value: T) -> Result<(), SetError<T>>;
+
+    /// Sets the result
+    ///
+    /// # Note
+    /// If the future has been cancelled, the value is dropped silently.
+    fn set(self, value: T) {
+        let _ = self.try_set(value);
+    }
+}
+
+/// The getter side of a future, implemented by [`Getter`] and [`crate::local::LocalGetter`]
+pub trait FutureGetter<T>: Sized {
+    /// Whether the future has been cancelled or not
+    fn is_cancelled(&self) -> bool;
+    /// Cancels the future
+    fn cancel(&self);
+    /// Checks if a result is available without blocking, returns either `Ok(Some(result))` if the future has completed
+    /// successfully, `Ok(None)` if the future has been cancelled, or `Err(self)` if the future is still pending
+    fn try_get(self) -> Result<Option<T>, Self>;
+}
+
+impl<T> FutureSetter<T> for Setter<T> {
+    fn is_cancelled(&self) -> bool {
+        Setter::is_cancelled(self)
+    }
+    fn try_set(self, value: T) -> Result<(), SetError<T>> {
+        Setter::try_set(self, value)
+    }
+}
+impl<T> FutureGetter<T> for Getter<T> {
+    fn is_cancelled(&self) -> bool {
+        Getter::is_cancelled(self)
+    }
+    fn cancel(&self) {
+        Getter::cancel(self)
+    }
+    fn try_get(self) -> Result<Option<T>, Self> {
+        Getter::try_get(self)
+    }
+}
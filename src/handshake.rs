@@ -0,0 +1,102 @@
+//! Implements a two-phase handshake where an offered value must be explicitly accepted or rejected
+
+use crate::{
+    future::{Getter, Setter},
+    outcome::{CancelOutcome, HandshakeResult},
+};
+use std::time::Duration;
+
+/// The offering side of a handshake, see [`handshake`]
+#[derive(Debug)]
+pub struct Offer<T> {
+    /// The setter for the offered value
+    value: Setter<T>,
+    /// The getter for the decision of the accepting side
+    reply: Getter<bool>,
+}
+impl<T> Offer<T> {
+    /// Offers `value` and blocks until the accepting side has either accepted or rejected it
+    ///
+    /// # Note
+    /// If the accepting side is dropped, the offer counts as rejected.
+    pub fn offer(self, value: T) -> HandshakeResult {
+        // Deliver the value; this fails if the accepting side has been dropped already
+        if self.value.try_set(value).is_err() {
+            return HandshakeResult::Rejected;
+        }
+        match self.reply.wait() {
+            Some(true) => HandshakeResult::Accepted,
+            _ => HandshakeResult::Rejected,
+        }
+    }
+    /// Offers `value` and blocks until the accepting side has either accepted or rejected it or the timeout is reached,
+    /// see [`Offer::offer`]
+    ///
+    /// # Note
+    /// On timeout, the offer is withdrawn unless the accepting side has decided in the meantime; a withdrawn offer can
+    /// never be accepted anymore.
+    pub fn offer_timeout(self, value: T, timeout: Duration) -> HandshakeResult {
+        // Deliver the value; this fails if the accepting side has been dropped already
+        if self.value.try_set(value).is_err() {
+            return HandshakeResult::Rejected;
+        }
+        let reply = match self.reply.wait_timeout(timeout) {
+            Ok(Some(true)) => return HandshakeResult::Accepted,
+            Ok(_) => return HandshakeResult::Rejected,
+            Err(reply) => reply,
+        };
+
+        // Withdraw the offer unless the accepting side has decided in the meantime
+        match reply.try_cancel() {
+            CancelOutcome::Cancelled => HandshakeResult::TimedOut,
+            CancelOutcome::AlreadySet if reply.wait() == Some(true) => HandshakeResult::Accepted,
+            CancelOutcome::AlreadySet | CancelOutcome::AlreadyCancelled => HandshakeResult::Rejected,
+        }
+    }
+}
+
+/// The accepting side of a handshake, see [`handshake`]
+#[derive(Debug)]
+pub struct Accept<T> {
+    /// The getter for the offered value
+    value: Getter<T>,
+    /// The setter for the decision
+    reply: Setter<bool>,
+}
+impl<T> Accept<T> {
+    /// Blocks until a value is offered and accepts it, returns either the value or `None` if the offering side has been
+    /// dropped or has withdrawn its offer
+    pub fn accept(self) -> Option<T> {
+        let value = self.value.wait()?;
+        Self::confirm(self.reply, value)
+    }
+    /// Blocks until a value is offered or the timeout is reached and accepts it, see [`Accept::accept`]
+    ///
+    /// # Note
+    /// On timeout, the accepting side is dropped, which rejects a later offer.
+    pub fn accept_timeout(self, timeout: Duration) -> Option<T> {
+        let value = self.value.wait_timeout(timeout).ok()??;
+        Self::confirm(self.reply, value)
+    }
+    /// Rejects the offer, or a future offer if no value has been offered yet
+    pub fn reject(self) {
+        self.reply.set(false);
+    }
+
+    /// Confirms the acceptance, returns either the value or `None` if the offer has been withdrawn in the meantime
+    fn confirm(reply: Setter<bool>, value: T) -> Option<T> {
+        reply.try_set(true).ok()?;
+        Some(value)
+    }
+}
+
+/// Creates a new handshake where the offering side blocks until the accepting side has explicitly accepted or rejected
+/// the offered value
+///
+/// # Note
+/// Dropping either side unblocks the other side with a rejection. Each side can be used exactly once.
+pub fn handshake<T>() -> (Offer<T>, Accept<T>) {
+    let (value_setter, value_getter) = crate::new();
+    let (reply_setter, reply_getter) = crate::new();
+    (Offer { value: value_setter, reply: reply_getter }, Accept { value: value_getter, reply: reply_setter })
+}
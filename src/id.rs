@@ -0,0 +1,29 @@
+//! Implements a stable identity token for futures
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::atomic::{AtomicU64, Ordering::Relaxed},
+};
+
+/// An opaque identity token that is shared by a setter and its getter
+///
+/// # Note
+/// Identifiers are derived from a process-wide counter and are never reused during the lifetime of the process.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FutureId {
+    /// The raw identifier
+    raw: u64,
+}
+impl FutureId {
+    /// Creates a new unique identifier
+    pub(in crate) fn next() -> Self {
+        /// The next raw identifier
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self { raw: NEXT.fetch_add(1, Relaxed) }
+    }
+}
+impl Debug for FutureId {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "FutureId({})", self.raw)
+    }
+}
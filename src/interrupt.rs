@@ -0,0 +1,39 @@
+//! Implements interrupters to wake threads that are blocked in an interruptible wait
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::Arc,
+};
+
+/// A type-erased handle to wake all threads that wait on a future
+pub(in crate) trait Notify: Send + Sync {
+    /// Wakes all threads that wait on the future so that they re-evaluate their wait conditions
+    fn notify(&self);
+}
+
+/// A handle to wake threads that are blocked in [`Getter::wait_interruptible`](crate::Getter::wait_interruptible)
+///
+/// # Note
+/// Notifying the interrupter does not cancel the future; it only wakes the waiting threads so that they re-check their
+/// stop flags. Set the stop flag _before_ calling [`Interrupter::interrupt`].
+#[derive(Clone)]
+pub struct Interrupter {
+    /// The underlying future
+    future: Arc<dyn Notify>,
+}
+impl Interrupter {
+    /// Creates a new interrupter
+    pub(in crate) fn new(future: Arc<dyn Notify>) -> Self {
+        Self { future }
+    }
+
+    /// Wakes all threads that are blocked in an interruptible wait on the associated future
+    pub fn interrupt(&self) {
+        self.future.notify();
+    }
+}
+impl Debug for Interrupter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interrupter").field("future", &"<opaque>").finish()
+    }
+}
@@ -0,0 +1,63 @@
+//! Implements a countdown latch with a fixed initial count
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Condvar, Mutex},
+    time::Duration,
+};
+
+/// A countdown latch that releases all waiting threads once it has been counted down to zero
+///
+/// # Note
+/// Unlike a [`crate::WaitGroup`], the count is fixed at construction. Counting down a latch that has already reached zero
+/// is a no-op. Share the latch via an [`std::sync::Arc`] or a scoped borrow.
+pub struct CountdownLatch {
+    /// The remaining count
+    count: Mutex<usize>,
+    /// The signal variable
+    signal: Condvar,
+}
+impl CountdownLatch {
+    /// Creates a new latch with the given count
+    pub const fn new(count: usize) -> Self {
+        Self { count: Mutex::new(count), signal: Condvar::new() }
+    }
+
+    /// Decrements the count and releases all waiting threads once it reaches zero
+    pub fn count_down(&self) {
+        // Ignore extra calls once the latch has been released
+        let mut count = self.count.lock().expect("The latch is poisoned?!");
+        let Some(remaining) = count.checked_sub(1) else {
+            return;
+        };
+
+        // Update the count and release the waiting threads if necessary
+        *count = remaining;
+        if remaining == 0 {
+            self.signal.notify_all();
+        }
+    }
+    /// The remaining count
+    pub fn current(&self) -> usize {
+        *self.count.lock().expect("The latch is poisoned?!")
+    }
+
+    /// Blocks until the count has reached zero
+    pub fn wait(&self) {
+        let count = self.count.lock().expect("The latch is poisoned?!");
+        let _count = self.signal.wait_while(count, |count| *count > 0).expect("The latch is poisoned?!");
+    }
+    /// Blocks until the count has reached zero or the timeout is reached, returns whether the count has reached zero
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let count = self.count.lock().expect("The latch is poisoned?!");
+        let (count, _) =
+            self.signal.wait_timeout_while(count, timeout, |count| *count > 0).expect("The latch is poisoned?!");
+        *count == 0
+    }
+}
+impl Debug for CountdownLatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let count = self.count.lock().ok().map(|count| *count);
+        f.debug_struct("CountdownLatch").field("count", &count).field("signal", &"<opaque>").finish()
+    }
+}
@@ -1,9 +1,104 @@
 #![doc = include_str!("../README.md")]
 
+mod any;
+#[cfg(any(feature = "futures-channel", feature = "tokio"))]
+mod bridge;
+mod builder;
+mod chunks;
+mod combinators;
+mod completion;
+mod consume;
+mod error;
+mod event;
+mod exchange;
+mod executor;
+#[cfg(all(unix, feature = "event-fd"))]
+mod fd;
 mod future;
+mod guard;
+mod handle;
+mod handshake;
+mod id;
+mod interrupt;
+mod latch;
+pub mod local;
+mod macros;
+mod multi;
+#[cfg(feature = "futures-channel")]
+mod oneshot;
+mod outcome;
+#[cfg(feature = "async")]
+mod poll;
+mod pool;
+mod process;
+mod progress;
+#[cfg(feature = "rayon")]
+pub mod rayon;
+mod reason;
+mod request;
+mod result;
+mod shared;
+mod signal;
+mod slot;
+mod spawn;
+mod timer;
+mod timing;
+#[cfg(feature = "tokio")]
+mod tokio;
+mod token;
+mod waitgroup;
+mod watch;
+#[cfg(all(windows, feature = "windows"))]
+mod win32;
 
 use crate::future::Future;
-pub use crate::future::{Getter, Setter};
+#[doc(hidden)]
+pub use crate::macros::private as __private;
+#[cfg(feature = "futures-channel")]
+pub use crate::oneshot::from_futures_oneshot;
+#[cfg(feature = "async")]
+pub use crate::poll::{CancelledFuture, GetterFuture};
+#[cfg(feature = "futures-compat")]
+pub use crate::poll::{GetterStream, TryGetterFuture};
+#[cfg(feature = "tokio")]
+pub use crate::tokio::from_tokio;
+pub use crate::{
+    any::AnyGetter,
+    builder::FutureBuilder,
+    chunks::{chunked, ChunkGetter, ChunkSetter},
+    combinators::{fold, join2, quorum, race, race_either, Fold},
+    completion::CompletionSet,
+    consume::set_unconsumed_hook,
+    error::{CancelReason, Cancelled, FutureError, JoinError, SetError},
+    event::Event,
+    exchange::{exchange, Left, Right},
+    executor::Executor,
+    future::{Getter, Setter, ValueGuard, WeakSetter},
+    guard::SetterGuard,
+    handle::{FutureGetter, FutureSetter},
+    handshake::{handshake, Accept, Offer},
+    id::FutureId,
+    interrupt::Interrupter,
+    latch::CountdownLatch,
+    multi::{first_ok, select2, try_join_all, wait_all, wait_all_deadline, wait_any},
+    outcome::{
+        CancelOutcome, DeadlineOutcome, Either, FutureState, HandshakeResult, InterruptOutcome, JoinOutcome, Selected,
+        WaitOutcome, WatchOutcome,
+    },
+    pool::FuturePool,
+    process::{from_child, from_child_output},
+    progress::{with_progress, ProgressSetter},
+    reason::{with_reason, ReasonGetter, ReasonSetter},
+    request::{request, Requester, Responder},
+    result::{new_result, ResultGetter, ResultSetter},
+    shared::{SharedFuture, SharedGetter},
+    slot::{slot, SlotGetter, SlotSetter},
+    spawn::{from_join_handle, spawn, spawn_scoped, spawn_with},
+    timer::{after, at, ScheduledCancel},
+    token::{CancelScope, CancellationToken},
+    waitgroup::{WaitGroup, WaitGroupGuard},
+    watch::{watch, WatchGetter, WatchSetter},
+};
 use std::sync::{atomic::AtomicBool, Arc};
 
 /// Creates a new future
@@ -17,3 +112,47 @@ pub fn new<T>() -> (Setter<T>, Getter<T>) {
     let getter = Getter::new(future, cancelled);
     (setter, getter)
 }
+
+/// Creates a new future that is cancelled if `token` is cancelled, see [`CancellationToken`]
+///
+/// # Note
+/// If the token has already been cancelled, the future is cancelled immediately.
+pub fn with_token<T>(token: &CancellationToken) -> (Setter<T>, Getter<T>)
+where
+    T: Send + 'static,
+{
+    let (setter, getter) = new();
+    token.bind(getter.weak_canceller());
+    (setter, getter)
+}
+
+/// Creates a future that has already completed with `value`
+pub fn ready<T>(value: T) -> Getter<T> {
+    let (setter, getter) = new();
+    setter.set(value);
+    getter
+}
+
+/// Creates a future that has already been cancelled
+pub fn cancelled<T>() -> Getter<T> {
+    let (setter, getter) = new();
+    setter.cancel();
+    getter
+}
+
+/// Creates a future whose result is computed by `f` on the thread that waits for it for the first time
+///
+/// # Note
+/// `f` runs on the first thread that waits for the result, e.g. via [`Getter::wait`], [`Getter::on_complete`], a
+/// combinator or a multi-wait like [`wait_all`]; it never runs if the future is cancelled before. Non-blocking checks like
+/// [`Getter::try_get`] do not evaluate `f` and report the future as pending until then. A panic in `f` is delivered to
+/// the getter as if [`Setter::complete_with`] was used.
+pub fn lazy<T, F>(f: F) -> Getter<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (setter, getter) = new();
+    setter.defer(f);
+    getter
+}
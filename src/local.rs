@@ -0,0 +1,175 @@
+//! Implements a single-threaded future for values that are not `Send`
+
+use crate::{
+    error::{Cancelled, SetError},
+    handle::{FutureGetter, FutureSetter},
+    outcome::FutureState,
+};
+use std::{
+    cell::RefCell,
+    fmt::{self, Debug, Formatter},
+    mem,
+    rc::Rc,
+};
+
+/// The state of a local future
+enum State<T> {
+    /// The future is pending
+    Pending,
+    /// The result has been set
+    Set(T),
+    /// The result has been claimed by the getter
+    Claimed,
+    /// The future has been cancelled
+    Cancelled(Cancelled),
+}
+
+/// Cancels a pending local future with the given reason
+fn cancel<T>(state: &RefCell<State<T>>, reason: Cancelled) {
+    let mut state = state.borrow_mut();
+    if matches!(*state, State::Pending) {
+        *state = State::Cancelled(reason);
+    }
+}
+
+/// A single-threaded setter for a future
+///
+/// # Note
+/// This type is neither `Send` nor `Sync`; dropping the setter without setting a result cancels the future.
+pub struct LocalSetter<T> {
+    /// The shared state
+    state: Rc<RefCell<State<T>>>,
+}
+impl<T> LocalSetter<T> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        matches!(*self.state.borrow(), State::Cancelled(_))
+    }
+    /// Cancels the future
+    pub fn cancel(&self) {
+        cancel(&self.state, Cancelled::Explicit);
+    }
+
+    /// Sets the result
+    ///
+    /// # Note
+    /// If the future has been cancelled, the value is dropped silently; use [`LocalSetter::try_set`] to recover it
+    /// instead.
+    pub fn set(self, value: T) {
+        let _ = self.try_set(value);
+    }
+    /// Sets the result, or hands the value back if the future has been cancelled
+    pub fn try_set(self, value: T) -> Result<(), SetError<T>> {
+        let mut state = self.state.borrow_mut();
+        match *state {
+            State::Pending => *state = State::Set(value),
+            State::Set(_) | State::Claimed => return Err(SetError::AlreadySet(value)),
+            State::Cancelled(reason) => return Err(SetError::Cancelled { value, reason }),
+        }
+        Ok(())
+    }
+}
+impl<T> FutureSetter<T> for LocalSetter<T> {
+    fn is_cancelled(&self) -> bool {
+        LocalSetter::is_cancelled(self)
+    }
+    fn try_set(self, value: T) -> Result<(), SetError<T>> {
+        LocalSetter::try_set(self, value)
+    }
+}
+impl<T> Debug for LocalSetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalSetter").field("cancelled", &self.is_cancelled()).finish()
+    }
+}
+impl<T> Drop for LocalSetter<T> {
+    fn drop(&mut self) {
+        cancel(&self.state, Cancelled::SetterDropped);
+    }
+}
+
+/// A single-threaded getter for a future
+///
+/// # Note
+/// This type is neither `Send` nor `Sync`; dropping the getter without claiming the result cancels the future.
+pub struct LocalGetter<T> {
+    /// The shared state
+    state: Rc<RefCell<State<T>>>,
+}
+impl<T> LocalGetter<T> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        matches!(*self.state.borrow(), State::Cancelled(_))
+    }
+    /// Cancels the future
+    pub fn cancel(&self) {
+        cancel(&self.state, Cancelled::Explicit);
+    }
+    /// The current state of the future
+    pub fn state(&self) -> FutureState {
+        match *self.state.borrow() {
+            State::Pending => FutureState::Pending,
+            State::Set(_) | State::Claimed => FutureState::Ready,
+            State::Cancelled(_) => FutureState::Cancelled,
+        }
+    }
+    /// Whether a result is available or not
+    pub fn is_ready(&self) -> bool {
+        self.state() == FutureState::Ready
+    }
+
+    /// Checks if a result is available, returns either `Ok(Some(result))` if the future has completed successfully,
+    /// `Ok(None)` if the future has been cancelled, or `Err(self)` if the future is still pending
+    pub fn try_get(self) -> Result<Option<T>, Self> {
+        self.try_get_result().map(Result::ok)
+    }
+    /// Checks if a result is available, returns either `Ok(Ok(result))` if the future has completed successfully,
+    /// `Ok(Err(reason))` if the future has been cancelled, or `Err(self)` if the future is still pending
+    pub fn try_get_result(self) -> Result<Result<T, Cancelled>, Self> {
+        // Claim the result if the future is resolved
+        let mut state = self.state.borrow_mut();
+        let result = match mem::replace(&mut *state, State::Claimed) {
+            State::Set(value) => Ok(value),
+            State::Cancelled(reason) => Err(reason),
+            pending => {
+                *state = pending;
+                drop(state);
+                return Err(self);
+            }
+        };
+
+        // Keep the future cancelled for the setter
+        if let Err(reason) = result {
+            *state = State::Cancelled(reason);
+        }
+        drop(state);
+        Ok(result)
+    }
+}
+impl<T> FutureGetter<T> for LocalGetter<T> {
+    fn is_cancelled(&self) -> bool {
+        LocalGetter::is_cancelled(self)
+    }
+    fn cancel(&self) {
+        LocalGetter::cancel(self)
+    }
+    fn try_get(self) -> Result<Option<T>, Self> {
+        LocalGetter::try_get(self)
+    }
+}
+impl<T> Debug for LocalGetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalGetter").field("state", &self.state()).finish()
+    }
+}
+impl<T> Drop for LocalGetter<T> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Creates a new single-threaded future
+pub fn new<T>() -> (LocalSetter<T>, LocalGetter<T>) {
+    let state = Rc::new(RefCell::new(State::Pending));
+    (LocalSetter { state: state.clone() }, LocalGetter { state })
+}
@@ -0,0 +1,200 @@
+//! Implements the blocking `join!` and `select!` macros
+
+/// Blocks until all given getters are resolved, evaluates to a tuple of their results (or `None` for cancelled futures)
+/// in the original order
+///
+/// # Note
+/// The getters may have different value types. The calling thread sleeps on a single shared signal instead of waiting
+/// on each future in turn; 2 through 8 getters are supported.
+///
+/// # Example
+/// ```
+/// let (setter_a, getter_a) = tiny_future::new::<u8>();
+/// let (setter_b, getter_b) = tiny_future::new::<&str>();
+/// setter_a.set(7);
+/// setter_b.set("seven");
+/// assert_eq!(tiny_future::join!(getter_a, getter_b), (Some(7), Some("seven")));
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($($getter:expr),+ $(,)?) => {
+        $crate::__private::Join::join(($($getter,)+))
+    };
+}
+
+/// Blocks until the first of several getters is resolved, and runs the block of its arm with the result (or `None` if
+/// the future has been cancelled) bound to the arm's pattern
+///
+/// # Note
+/// Every arm has the form `pattern = getter => { ... }`, where `getter` is a place of type [`crate::Getter`]; an
+/// optional last arm `timeout(duration) => { ... }` runs if no getter is resolved in time. If several getters are already
+/// resolved, the first arm in source order wins. Only the getter of the fired arm is claimed; it reports the future as
+/// cancelled afterwards, while the other getters are left untouched and can be waited on again.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// let (setter_a, mut getter_a) = tiny_future::new::<u8>();
+/// let (_setter_b, mut getter_b) = tiny_future::new::<&str>();
+/// setter_a.set(7);
+///
+/// let value = tiny_future::select! {
+///     value = getter_a => { value },
+///     _ = getter_b => { None },
+///     timeout(Duration::from_secs(1)) => { None },
+/// };
+/// assert_eq!(value, Some(7));
+/// ```
+#[macro_export]
+macro_rules! select {
+    // Collect the arms
+    (@arms [$($arms:tt)*] timeout($timeout:expr) => $body:block $(,)?) => {
+        $crate::select!(@expand [$($arms)*] ::std::option::Option::Some($timeout), $body)
+    };
+    (@arms [$($arms:tt)*] $pattern:pat = $getter:expr => $body:block $(, $($rest:tt)*)?) => {
+        $crate::select!(@arms [$($arms)* ($pattern, $getter, $body)] $($($rest)*)?)
+    };
+    (@arms [$($arms:tt)*]) => {
+        $crate::select!(@expand [$($arms)*] ::std::option::Option::None, { ::std::unreachable!() })
+    };
+
+    // Wait for the first arm and dispatch it
+    (@expand [$(($pattern:pat, $getter:expr, $body:block))+] $timeout:expr, $timeout_body:block) => {{
+        let fired = $crate::__private::select(&[$(&$getter),+], $timeout);
+        let mut arm = 0usize;
+        $(
+            if { arm += 1; fired == ::std::option::Option::Some(arm - 1) } {
+                let $pattern = $crate::__private::claim(&mut $getter);
+                $body
+            } else
+        )+
+        $timeout_body
+    }};
+
+    // Entry point
+    ($($arms:tt)+) => {
+        $crate::select!(@arms [] $($arms)+)
+    };
+}
+
+/// Support items for the macros; not part of the public API
+pub mod private {
+    use crate::{future::Getter, outcome::FutureState, signal::Signal};
+    use std::{
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    /// A shared signal that is notified if any watched future is resolved
+    #[derive(Debug, Default)]
+    pub struct Watcher {
+        /// The underlying signal
+        signal: Arc<Signal>,
+    }
+    impl Watcher {
+        /// Waits until the signal has been notified
+        pub fn wait(&self) {
+            self.signal.wait();
+        }
+    }
+
+    /// A type-erased getter that can be watched
+    pub trait Watch {
+        /// Registers the watcher
+        fn watch(&self, watcher: &Watcher);
+        /// Unregisters the watcher
+        fn unwatch(&self, watcher: &Watcher);
+        /// Whether the future is still pending or not
+        fn is_pending(&self) -> bool;
+    }
+    impl<T> Watch for Getter<T> {
+        fn watch(&self, watcher: &Watcher) {
+            Getter::watch(self, &watcher.signal);
+        }
+        fn unwatch(&self, watcher: &Watcher) {
+            Getter::unwatch(self, &watcher.signal);
+        }
+        fn is_pending(&self) -> bool {
+            self.state() == FutureState::Pending
+        }
+    }
+
+    /// Blocks until any of `getters` is resolved or the timeout is reached, returns the index of the first resolved getter
+    /// or `None` on timeout
+    pub fn select(getters: &[&dyn Watch], timeout: Option<Duration>) -> Option<usize> {
+        // Register a shared signal before checking the states so that no resolution can be missed
+        let watcher = Watcher::default();
+        for getter in getters {
+            getter.watch(&watcher);
+        }
+
+        // Wait until any getter is resolved or the deadline is reached
+        let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+        let fired = loop {
+            let resolved = getters.iter().position(|getter| !getter.is_pending());
+            match deadline {
+                _ if resolved.is_some() => break resolved,
+                Some(deadline) if !watcher.signal.wait_deadline(deadline) => {
+                    // Check the states a last time since a getter may have been resolved right at the deadline
+                    break getters.iter().position(|getter| !getter.is_pending());
+                }
+                Some(_) => continue,
+                None => watcher.wait(),
+            }
+        };
+
+        // Unregister the signal
+        for getter in getters {
+            getter.unwatch(&watcher);
+        }
+        fired
+    }
+    /// Claims the result of a resolved getter, see [`crate::select!`]
+    pub fn claim<T>(getter: &mut Getter<T>) -> Option<T> {
+        getter.take_if_ready().flatten()
+    }
+
+    /// Blocks until all `getters` are resolved
+    fn wait_all(getters: &[&dyn Watch]) {
+        // Register a shared signal before checking the states so that no resolution can be missed
+        let watcher = Watcher::default();
+        for getter in getters {
+            getter.watch(&watcher);
+        }
+
+        // Wait until all getters are resolved and unregister the signal
+        while getters.iter().any(|getter| getter.is_pending()) {
+            watcher.wait();
+        }
+        for getter in getters {
+            getter.unwatch(&watcher);
+        }
+    }
+
+    /// A tuple of getters that can be joined, see [`crate::join!`]
+    pub trait Join {
+        /// The tuple of results
+        type Output;
+        /// Blocks until all getters are resolved and claims their results
+        fn join(self) -> Self::Output;
+    }
+    /// Implements `Join` for a tuple of getters
+    macro_rules! impl_join {
+        ($($value:ident $index:tt),+) => {
+            impl<$($value),+> Join for ($(Getter<$value>,)+) {
+                type Output = ($(Option<$value>,)+);
+                fn join(self) -> Self::Output {
+                    wait_all(&[$(&self.$index),+]);
+                    ($(self.$index.wait(),)+)
+                }
+            }
+        };
+    }
+    impl_join!(A 0, B 1);
+    impl_join!(A 0, B 1, C 2);
+    impl_join!(A 0, B 1, C 2, D 3);
+    impl_join!(A 0, B 1, C 2, D 3, E 4);
+    impl_join!(A 0, B 1, C 2, D 3, E 4, F 5);
+    impl_join!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    impl_join!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+}
@@ -0,0 +1,200 @@
+//! Implements wait operations on multiple futures at once
+
+use crate::{
+    error::JoinError,
+    future::Getter,
+    outcome::{FutureState, Selected},
+    signal::Signal,
+};
+use std::{sync::Arc, time::Instant};
+
+/// Blocks until the first of `getters` is resolved, returns its index, its result (or `None` if it has been
+/// cancelled), and the remaining getters in their original order
+///
+/// # Note
+/// If several getters are already resolved, the one with the lowest index is picked. The remaining getters are left
+/// untouched and can be waited on again.
+///
+/// # Panics
+/// This function panics if `getters` is empty.
+pub fn wait_any<T>(mut getters: Vec<Getter<T>>) -> (usize, Option<T>, Vec<Getter<T>>) {
+    assert!(!getters.is_empty(), "Cannot wait on an empty set of futures");
+
+    // Register a shared signal before checking the states so that no resolution can be missed
+    let signal = Arc::new(Signal::default());
+    for getter in getters.iter() {
+        getter.watch(&signal);
+    }
+
+    // Wait until any getter is resolved
+    let index = loop {
+        let resolved = getters.iter().position(|getter| getter.state() != FutureState::Pending);
+        match resolved {
+            Some(index) => break index,
+            None => signal.wait(),
+        }
+    };
+
+    // Unregister the signal and claim the result
+    for getter in getters.iter() {
+        getter.unwatch(&signal);
+    }
+    let result = getters.remove(index).wait();
+    (index, result, getters)
+}
+
+/// Blocks until either of two futures of different types is resolved, returns the result of the resolved side (or
+/// `None` if it has been cancelled) and hands back the other side
+///
+/// # Note
+/// If both futures are already resolved, the left one is picked. The other side is left untouched and can be waited on
+/// again, which makes `select2(data, shutdown)` in a loop cheap.
+pub fn select2<A, B>(a: Getter<A>, b: Getter<B>) -> Selected<A, B> {
+    // Register a shared signal before checking the states so that no resolution can be missed
+    let signal = Arc::new(Signal::default());
+    a.watch(&signal);
+    b.watch(&signal);
+
+    // Wait until either getter is resolved
+    let left = loop {
+        match (a.state(), b.state()) {
+            (FutureState::Pending, FutureState::Pending) => signal.wait(),
+            (FutureState::Pending, _) => break false,
+            _ => break true,
+        }
+    };
+
+    // Unregister the signal and claim the result
+    a.unwatch(&signal);
+    b.unwatch(&signal);
+    match left {
+        true => Selected::Left(a.wait(), b),
+        false => Selected::Right(b.wait(), a),
+    }
+}
+
+/// Blocks until all `getters` are resolved, returns their results (or `None` for cancelled futures) in the original
+/// order
+///
+/// # Note
+/// The calling thread sleeps on a single shared signal instead of waiting on each future in turn. If a setter is leaked
+/// without ever being dropped, this function blocks forever; use [`wait_all_deadline`] to bound the wait.
+pub fn wait_all<T>(getters: Vec<Getter<T>>) -> Vec<Option<T>> {
+    // Register a shared signal before checking the states so that no resolution can be missed
+    let signal = Arc::new(Signal::default());
+    for getter in getters.iter() {
+        getter.watch(&signal);
+    }
+
+    // Wait until all getters are resolved
+    while getters.iter().any(|getter| getter.state() == FutureState::Pending) {
+        signal.wait();
+    }
+
+    // Unregister the signal and claim the results
+    for getter in getters.iter() {
+        getter.unwatch(&signal);
+    }
+    getters.into_iter().map(Getter::wait).collect()
+}
+/// Blocks until all `getters` are resolved or the deadline is reached, returns either their results (or `None` for
+/// cancelled futures) in the original order, or all getters if the deadline has been reached
+pub fn wait_all_deadline<T>(getters: Vec<Getter<T>>, deadline: Instant) -> Result<Vec<Option<T>>, Vec<Getter<T>>> {
+    // Register a shared signal before checking the states so that no resolution can be missed
+    let signal = Arc::new(Signal::default());
+    for getter in getters.iter() {
+        getter.watch(&signal);
+    }
+
+    // Wait until all getters are resolved or the deadline is reached
+    let mut resolved = false;
+    while !resolved {
+        resolved = getters.iter().all(|getter| getter.state() != FutureState::Pending);
+        if !resolved && !signal.wait_deadline(deadline) {
+            break;
+        }
+    }
+
+    // Unregister the signal and claim the results if they are pending
+    for getter in getters.iter() {
+        getter.unwatch(&signal);
+    }
+    match resolved {
+        true => Ok(getters.into_iter().map(Getter::wait).collect()),
+        false => Err(getters),
+    }
+}
+
+/// Blocks until all `getters` have completed successfully and returns their results in the original order, or returns
+/// early as soon as any of them is cancelled
+///
+/// # Note
+/// On early return, all remaining getters are cancelled so that their producers can stop working. If several getters
+/// are already cancelled, the error reports the lowest index.
+pub fn try_join_all<T>(getters: Vec<Getter<T>>) -> Result<Vec<T>, JoinError> {
+    // Register a shared signal before checking the states so that no resolution can be missed
+    let signal = Arc::new(Signal::default());
+    for getter in getters.iter() {
+        getter.watch(&signal);
+    }
+
+    // Wait until all getters are ready or any getter is cancelled
+    let cancelled = loop {
+        let states: Vec<_> = getters.iter().map(Getter::state).collect();
+        if let Some(index) = states.iter().position(|state| *state == FutureState::Cancelled) {
+            break Some(index);
+        }
+        if states.iter().all(|state| *state == FutureState::Ready) {
+            break None;
+        }
+        signal.wait();
+    };
+
+    // Unregister the signal
+    for getter in getters.iter() {
+        getter.unwatch(&signal);
+    }
+
+    // Claim the results or cancel the remaining getters by dropping them
+    match cancelled {
+        Some(index) => Err(JoinError { index }),
+        None => Ok(getters.into_iter().filter_map(Getter::wait).collect()),
+    }
+}
+
+/// Blocks until the first of `getters` completes with `Ok`, or returns the collected errors in the original order (or
+/// `None` for cancelled futures) if all of them have failed
+///
+/// # Note
+/// As soon as an `Ok` arrives, all remaining getters are cancelled. If several getters are already completed with
+/// `Ok`, the one with the lowest index is picked.
+pub fn first_ok<T, E>(getters: Vec<Getter<Result<T, E>>>) -> Result<T, Vec<Option<E>>> {
+    // Register a shared signal before checking the states so that no resolution can be missed
+    let signal = Arc::new(Signal::default());
+    for getter in getters.iter() {
+        getter.watch(&signal);
+    }
+
+    // Collect the failures until an `Ok` arrives or all getters have failed
+    let mut errors: Vec<Option<E>> = getters.iter().map(|_| None).collect();
+    let mut pending: Vec<Option<Getter<Result<T, E>>>> = getters.into_iter().map(Some).collect();
+    while pending.iter().any(Option::is_some) {
+        // Claim the results of all resolved getters
+        for (index, slot) in pending.iter_mut().enumerate() {
+            let Some(getter) = slot.take_if(|getter| getter.state() != FutureState::Pending) else {
+                continue;
+            };
+            match getter.wait() {
+                Some(Ok(value)) => return Ok(value),
+                Some(Err(error)) => errors[index] = Some(error),
+                None => errors[index] = None,
+            }
+        }
+
+        // Wait for further resolutions
+        if pending.iter().any(Option::is_some) {
+            signal.wait();
+        }
+    }
+    Err(errors)
+}
@@ -0,0 +1,49 @@
+//! Implements bridges between futures and `futures::channel::oneshot` channels
+
+use crate::{bridge::OneshotSender, future::Getter};
+use futures_channel::oneshot::{self, Receiver, Sender};
+use std::task::{Context, Poll};
+
+impl<T> OneshotSender for Sender<T>
+where
+    T: Send,
+{
+    type Value = T;
+
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.poll_canceled(cx)
+    }
+    fn send(self, value: Self::Value) {
+        // The receiver may be gone already, in which case the value is simply dropped
+        let _ = Sender::send(self, value);
+    }
+}
+
+/// Creates a getter that resolves to the value received from `receiver`
+///
+/// # Note
+/// No thread or runtime is involved: the future is completed on the thread that sends the value, and cancelled if the
+/// receiver resolves to `Canceled`. If the getter is dropped or cancelled, the receiver is dropped, so the sender's
+/// `cancellation()` future fires.
+pub fn from_futures_oneshot<T>(receiver: Receiver<T>) -> Getter<T>
+where
+    T: Send + 'static,
+{
+    crate::bridge::receive(receiver)
+}
+
+impl<T> Getter<T>
+where
+    T: Send + 'static,
+{
+    /// Converts the getter into a `futures` receiver
+    ///
+    /// # Note
+    /// No thread or runtime is involved: the result is sent on the thread that completes the future, and the receiver
+    /// resolves to `Canceled` if the future is cancelled. If the receiver is dropped or closed, the future is cancelled.
+    pub fn into_futures_oneshot(self) -> Receiver<T> {
+        let (sender, receiver) = oneshot::channel();
+        crate::bridge::forward(self, sender);
+        receiver
+    }
+}
@@ -0,0 +1,116 @@
+//! Implements the state and outcome types of the various future operations
+
+use crate::future::Getter;
+use std::any::Any;
+
+/// The outcome of a bounded wait operation
+#[derive(Debug)]
+pub enum WaitOutcome<T> {
+    /// The future has completed successfully
+    Ready(T),
+    /// The future has been cancelled
+    Cancelled,
+    /// The timeout has been reached before the future has completed; contains the getter so that the caller can retry
+    TimedOut(Getter<T>),
+}
+
+/// The outcome of a wait operation that reports a panic of the setter, see [`crate::Getter::wait_outcome`]
+#[derive(Debug)]
+pub enum JoinOutcome<T> {
+    /// The future has completed successfully
+    Ready(T),
+    /// The future has been cancelled
+    Cancelled,
+    /// The setter has panicked while computing the result; contains the panic payload, see
+    /// [`crate::Setter::complete_with`]
+    Panicked(Box<dyn Any + Send>),
+}
+
+/// The outcome of a wait operation that is bounded by the deadline announced by the setter, see
+/// [`crate::Getter::wait_until_deadline`]
+#[derive(Debug)]
+pub enum DeadlineOutcome<T> {
+    /// The future has completed successfully
+    Ready(T),
+    /// The future has been cancelled
+    Cancelled,
+    /// The deadline announced by the setter has been reached before the future has completed; contains the getter so
+    /// that the caller can retry
+    DeadlineExceeded(Getter<T>),
+}
+
+/// The outcome of an interruptible wait operation, see [`crate::Getter::wait_interruptible`]
+#[derive(Debug)]
+pub enum InterruptOutcome<T> {
+    /// The future has completed successfully
+    Ready(T),
+    /// The future has been cancelled
+    Cancelled,
+    /// The wait has been interrupted before the future has completed; contains the getter so that the caller can retry
+    Interrupted(Getter<T>),
+}
+
+/// The outcome of an explicit cancellation, see [`crate::Setter::try_cancel`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CancelOutcome {
+    /// The future has been cancelled by this call
+    Cancelled,
+    /// The future has not been cancelled since a result has already been set
+    AlreadySet,
+    /// The future has not been cancelled since it has already been cancelled before
+    AlreadyCancelled,
+}
+
+/// The outcome of an offer, see [`crate::Offer::offer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HandshakeResult {
+    /// The accepting side has accepted the offered value
+    Accepted,
+    /// The accepting side has rejected the offered value or has been dropped
+    Rejected,
+    /// The timeout has been reached and the offer has been withdrawn before the accepting side could decide
+    TimedOut,
+}
+
+/// The observable state of a future
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FutureState {
+    /// The future has neither completed nor been cancelled yet
+    Pending,
+    /// The future has completed and the result is available
+    ///
+    /// # Note
+    /// Once a result is available, the future cannot be cancelled anymore.
+    Ready,
+    /// The future has been cancelled without a result
+    Cancelled,
+}
+
+/// The outcome of [`crate::select2`], tells which side has been resolved and hands back the other, still pending side
+#[derive(Debug)]
+pub enum Selected<A, B> {
+    /// The left future has been resolved with `Some(result)`, or `None` if it has been cancelled
+    Left(Option<A>, Getter<B>),
+    /// The right future has been resolved with `Some(result)`, or `None` if it has been cancelled
+    Right(Option<B>, Getter<A>),
+}
+
+/// The outcome of waiting for a change of a watch channel, see [`crate::WatchGetter::wait_changed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchOutcome {
+    /// A new value has been published since the last seen version
+    Changed,
+    /// The setter has been closed without publishing a new value
+    Closed,
+    /// The timeout has been reached before a new value has been published
+    TimedOut,
+}
+
+/// A value that is either of type `A` or of type `B`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<A, B> {
+    /// The left value
+    Left(A),
+    /// The right value
+    Right(B),
+}
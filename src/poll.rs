@@ -0,0 +1,255 @@
+//! Implements an adapter that allows awaiting getters from async code
+
+#[cfg(feature = "futures-compat")]
+use crate::error::FutureError;
+use crate::{
+    error::Cancelled,
+    future::{Getter, Setter, WakerSlot},
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    future::{Future, IntoFuture},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// An adapter that implements `std::future::Future` for a getter, see [`Getter::into_async`]
+///
+/// # Note
+/// The task is woken if the future is completed or cancelled, so no thread is blocked while awaiting the result. Once
+/// the result has been returned, the adapter is terminated and subsequent polls stay pending. A deferred computation
+/// (see [`crate::lazy`]) is evaluated on the polling thread.
+pub struct GetterFuture<T> {
+    /// The underlying getter
+    getter: Getter<T>,
+    /// Whether the result has been returned already or not
+    terminated: bool,
+}
+impl<T> GetterFuture<T> {
+    /// Whether the result has been returned already or not
+    pub fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+    /// Converts the adapter back into the underlying getter
+    pub fn into_inner(self) -> Getter<T> {
+        self.getter
+    }
+
+    /// Polls the getter, returns either the result or the reason why the future has been cancelled
+    fn poll_result(&mut self, cx: &mut Context<'_>) -> Poll<Result<T, Cancelled>> {
+        // Never resolve a second time
+        if self.terminated {
+            return Poll::Pending;
+        }
+        match self.getter.poll_ready(cx.waker()) {
+            Some(result) => {
+                self.terminated = true;
+                Poll::Ready(result)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+impl<T> Future for GetterFuture<T> {
+    /// Either `Some(result)` if the future has completed successfully or `None` if the future has been cancelled
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().poll_result(cx).map(Result::ok)
+    }
+}
+impl<T> Debug for GetterFuture<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetterFuture").field("getter", &self.getter).field("terminated", &self.terminated).finish()
+    }
+}
+
+#[cfg(feature = "futures-compat")]
+impl<T> futures_core::FusedFuture for GetterFuture<T> {
+    fn is_terminated(&self) -> bool {
+        self.terminated
+    }
+}
+
+/// An adapter that awaits a fallible getter and flattens cancellation and failure into one error
+///
+/// # Note
+/// `futures_core::TryFuture` is implemented automatically for every future that resolves to a `Result`, so this adapter
+/// is the way to use a getter with `TryFutureExt` and friends.
+#[cfg(feature = "futures-compat")]
+pub struct TryGetterFuture<T, E> {
+    /// The underlying adapter
+    inner: GetterFuture<Result<T, E>>,
+}
+#[cfg(feature = "futures-compat")]
+impl<T, E> TryGetterFuture<T, E> {
+    /// Converts the adapter back into the underlying getter
+    pub fn into_inner(self) -> Getter<Result<T, E>> {
+        self.inner.into_inner()
+    }
+}
+#[cfg(feature = "futures-compat")]
+impl<T, E> Future for TryGetterFuture<T, E> {
+    /// Either the value, the error or the reason why the future has been cancelled, see [`crate::ResultGetter::wait`]
+    type Output = Result<T, FutureError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().inner.poll_result(cx).map(|result| match result {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(error)) => Err(FutureError::Failed(error)),
+            Err(reason) => Err(FutureError::Cancelled(reason)),
+        })
+    }
+}
+#[cfg(feature = "futures-compat")]
+impl<T, E> futures_core::FusedFuture for TryGetterFuture<T, E> {
+    fn is_terminated(&self) -> bool {
+        self.inner.terminated
+    }
+}
+#[cfg(feature = "futures-compat")]
+impl<T, E> Debug for TryGetterFuture<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryGetterFuture").field("inner", &self.inner).finish()
+    }
+}
+
+/// An adapter that implements `futures_core::Stream` for a getter, see [`Getter::into_stream`]
+///
+/// # Note
+/// The stream yields exactly one item if the future completes and ends afterwards, or ends without an item if the
+/// future is cancelled. Wakeups are handled exactly like for [`GetterFuture`].
+#[cfg(feature = "futures-compat")]
+pub struct GetterStream<T> {
+    /// The underlying adapter
+    inner: GetterFuture<T>,
+}
+#[cfg(feature = "futures-compat")]
+impl<T> GetterStream<T> {
+    /// Converts the adapter back into the underlying getter
+    pub fn into_inner(self) -> Getter<T> {
+        self.inner.into_inner()
+    }
+}
+#[cfg(feature = "futures-compat")]
+impl<T> futures_core::Stream for GetterStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // End the stream once the result has been yielded
+        let this = self.get_mut();
+        if this.inner.terminated {
+            return Poll::Ready(None);
+        }
+        this.inner.poll_result(cx).map(Result::ok)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.inner.terminated {
+            true => (0, Some(0)),
+            false => (0, Some(1)),
+        }
+    }
+}
+#[cfg(feature = "futures-compat")]
+impl<T> Debug for GetterStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetterStream").field("inner", &self.inner).finish()
+    }
+}
+
+/// A future that resolves once the getter has cancelled the future, see [`Setter::cancelled`]
+///
+/// # Note
+/// Like [`Setter::wait_cancelled`], the future also resolves if another setter sets the result in the meantime. The
+/// task is woken by the thread that cancels the future, so no thread is blocked.
+pub struct CancelledFuture<'a, T> {
+    /// The underlying setter
+    setter: &'a Setter<T>,
+    /// The waker slot once the future has been polled
+    slot: Option<Arc<WakerSlot>>,
+}
+impl<T> Future for CancelledFuture<'_, T> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        match this.setter.poll_cancelled(&mut this.slot, cx.waker()) {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    }
+}
+impl<T> Debug for CancelledFuture<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelledFuture").field("setter", &self.setter).finish()
+    }
+}
+
+impl<T> Setter<T> {
+    /// Creates a future that resolves once the getter cancels the future, or immediately if the future has been
+    /// cancelled already, see [`CancelledFuture`]
+    ///
+    /// # Note
+    /// This is the async counterpart of [`Setter::wait_cancelled`]; it allows an async producer to stop its work as
+    /// soon as the consumer loses interest, e.g. by racing the work against the cancellation.
+    pub fn cancelled(&self) -> CancelledFuture<'_, T> {
+        CancelledFuture { setter: self, slot: None }
+    }
+}
+
+impl<T> Getter<T> {
+    /// Converts the getter into an adapter that can be awaited from async code, see [`GetterFuture`]
+    pub fn into_async(self) -> GetterFuture<T> {
+        GetterFuture { getter: self, terminated: false }
+    }
+    /// Converts the getter into a stream of at most one item, see [`GetterStream`]
+    #[cfg(feature = "futures-compat")]
+    pub fn into_stream(self) -> GetterStream<T> {
+        GetterStream { inner: self.into_async() }
+    }
+}
+impl<T> IntoFuture for Getter<T> {
+    type Output = Option<T>;
+    type IntoFuture = GetterFuture<T>;
+
+    /// Converts the getter into an adapter so that it can be awaited directly, see [`GetterFuture`]
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{future::Future, pin::pin, sync::Arc, task::{Context, Poll, Wake, Waker}, thread::{self, Thread}};
+    /// # struct Unpark(Thread);
+    /// # impl Wake for Unpark {
+    /// #     fn wake(self: Arc<Self>) {
+    /// #         self.0.unpark();
+    /// #     }
+    /// # }
+    /// # fn block_on<F: Future>(future: F) -> F::Output {
+    /// #     let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    /// #     let (mut future, mut cx) = (pin!(future), Context::from_waker(&waker));
+    /// #     loop {
+    /// #         match future.as_mut().poll(&mut cx) {
+    /// #             Poll::Ready(output) => return output,
+    /// #             Poll::Pending => thread::park(),
+    /// #         }
+    /// #     }
+    /// # }
+    /// // Complete the future from a plain thread
+    /// let (setter, getter) = tiny_future::new::<u8>();
+    /// thread::spawn(move || setter.set(7));
+    ///
+    /// // Await the getter from async code
+    /// let result = block_on(async move { getter.await });
+    /// assert_eq!(result, Some(7));
+    /// ```
+    fn into_future(self) -> Self::IntoFuture {
+        self.into_async()
+    }
+}
+#[cfg(feature = "futures-compat")]
+impl<T, E> Getter<Result<T, E>> {
+    /// Converts the getter into an adapter that resolves to a flat `Result`, see [`TryGetterFuture`]
+    pub fn into_try_async(self) -> TryGetterFuture<T, E> {
+        TryGetterFuture { inner: self.into_async() }
+    }
+}
@@ -0,0 +1,82 @@
+//! Implements a pool that recycles the allocations of completed futures
+
+use crate::future::{Future, Getter, Setter};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{atomic::AtomicBool, Arc, Mutex},
+};
+
+/// An idle future together with its cancellation flag
+type Cell<T> = (Arc<Future<T>>, Arc<AtomicBool>);
+
+/// The idle futures of a pool
+pub(in crate) struct Recycler<T> {
+    /// The futures that have been returned to the pool
+    idle: Mutex<Vec<Cell<T>>>,
+}
+impl<T> Recycler<T> {
+    /// Returns a future whose handles have all been dropped to the pool
+    pub(in crate) fn recycle(&self, future: Arc<Future<T>>, cancelled: Arc<AtomicBool>) {
+        self.idle.lock().expect("The pool is poisoned?!").push((future, cancelled));
+    }
+}
+
+/// A pool that reuses the allocations of futures whose setters and getter have all been dropped
+///
+/// # Note
+/// A future is only reused if the pool holds the last reference to it, so a handle from a previous life (e.g. a
+/// [`crate::WeakSetter`]) can never observe the reused future; such futures are discarded instead. Every reuse starts
+/// from a fresh pending state with a new [`crate::FutureId`], so no stale value or cancellation carries over.
+pub struct FuturePool<T> {
+    /// The idle futures
+    recycler: Arc<Recycler<T>>,
+}
+impl<T> FuturePool<T> {
+    /// Creates a new empty pool
+    pub fn new() -> Self {
+        Self { recycler: Arc::new(Recycler { idle: Mutex::default() }) }
+    }
+
+    /// The amount of idle futures that have been returned to the pool
+    pub fn idle(&self) -> usize {
+        self.recycler.idle.lock().expect("The pool is poisoned?!").len()
+    }
+
+    /// Creates a new future, reusing an idle allocation if possible
+    pub fn acquire(&self) -> (Setter<T>, Getter<T>) {
+        let (future, cancelled) = self.reuse().unwrap_or_else(|| {
+            // Allocate a new future that is returned to this pool
+            let future = Arc::new(Future::pooled(Arc::downgrade(&self.recycler)));
+            (future, Arc::default())
+        });
+
+        // Create the setter/getter pair
+        let setter = Setter::new(future.clone(), cancelled.clone());
+        let getter = Getter::new(future, cancelled);
+        (setter, getter)
+    }
+    /// Takes an idle future that is referenced by nobody else and resets it to the pending state
+    fn reuse(&self) -> Option<Cell<T>> {
+        loop {
+            // Take the next idle future outside of the lock, since resetting drops the previous state
+            let (mut future, mut cancelled) = self.recycler.idle.lock().expect("The pool is poisoned?!").pop()?;
+
+            // Only reuse the future if nobody else can observe it; discard it otherwise
+            if let (Some(inner), Some(flag)) = (Arc::get_mut(&mut future), Arc::get_mut(&mut cancelled)) {
+                *inner = Future::pooled(Arc::downgrade(&self.recycler));
+                *flag.get_mut() = false;
+                return Some((future, cancelled));
+            }
+        }
+    }
+}
+impl<T> Default for FuturePool<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> Debug for FuturePool<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FuturePool").field("idle", &self.idle()).finish()
+    }
+}
@@ -0,0 +1,88 @@
+//! Implements futures that complete once a child process exits
+
+use crate::future::{Getter, Setter};
+use std::{
+    io::{self, Read},
+    process::{Child, ExitStatus, Output},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+/// The interval to poll the child process for its exit status
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Waits until the child process exits or the future is cancelled, returns either the exit status or `None` if the future
+/// has been cancelled or the child cannot be waited for
+///
+/// # Note
+/// If the future is cancelled, the child process is killed and reaped so that it does not linger as a zombie.
+fn supervise<T>(child: &mut Child, setter: &Setter<T>) -> Option<ExitStatus> {
+    loop {
+        // Check whether the child has exited
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status),
+            Ok(None) => (),
+            Err(_) => return None,
+        }
+
+        // Wait for a cancellation until the next poll
+        if setter.wait_cancelled_timeout(POLL_INTERVAL) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return None;
+        }
+    }
+}
+
+/// Reads `pipe` to the end on a helper thread
+fn drain<R>(pipe: Option<R>) -> JoinHandle<io::Result<Vec<u8>>>
+where
+    R: Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut buf)?;
+        }
+        Ok(buf)
+    })
+}
+
+/// Creates a future that completes with the exit status of `child` once it exits
+///
+/// # Note
+/// The child is supervised on a helper thread. If the future is cancelled before the child exits, the child is killed
+/// and reaped; if the child cannot be waited for, the future is cancelled.
+pub fn from_child(mut child: Child) -> Getter<ExitStatus> {
+    let (setter, getter) = crate::new();
+    thread::spawn(move || {
+        if let Some(status) = supervise(&mut child, &setter) {
+            setter.set(status);
+        }
+    });
+    getter
+}
+
+/// Creates a future that completes with the exit status and the captured output of `child` once it exits, see
+/// [`from_child`]
+///
+/// # Note
+/// Only the streams that have been configured as [`std::process::Stdio::piped`] are captured; the other streams are
+/// reported as empty. If a stream cannot be read, the future is cancelled.
+pub fn from_child_output(mut child: Child) -> Getter<Output> {
+    let (setter, getter) = crate::new();
+    thread::spawn(move || {
+        // Drain the pipes concurrently so that the child does not block on a full pipe
+        let stdout = drain(child.stdout.take());
+        let stderr = drain(child.stderr.take());
+        let status = supervise(&mut child, &setter);
+
+        // Collect the output; the pipes are closed once the child has exited or has been killed
+        let stdout = stdout.join().ok().and_then(Result::ok);
+        let stderr = stderr.join().ok().and_then(Result::ok);
+        if let (Some(status), Some(stdout), Some(stderr)) = (status, stdout, stderr) {
+            setter.set(Output { status, stdout, stderr });
+        }
+    });
+    getter
+}
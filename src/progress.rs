@@ -0,0 +1,60 @@
+//! Implements progress reporting alongside a future
+
+use crate::future::{Getter, Setter};
+use std::{
+    any::Any,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// A type-erased handle to store the latest progress of a future
+pub(in crate) trait Report: Send + Sync {
+    /// Stores the latest progress and wakes waiting threads, unless the future has been resolved already
+    fn report(&self, progress: Box<dyn Any + Send>);
+}
+
+/// A handle to report the progress of a future, see [`with_progress`]
+///
+/// # Note
+/// Progress updates that are reported after the future has been completed or cancelled are ignored.
+pub struct ProgressSetter<P> {
+    /// The underlying future
+    future: Arc<dyn Report>,
+    /// The type of the progress
+    _progress: PhantomData<fn(P)>,
+}
+impl<P> ProgressSetter<P>
+where
+    P: Send + 'static,
+{
+    /// Reports the latest progress, replacing the previous progress
+    pub fn report(&self, progress: P) {
+        self.future.report(Box::new(progress));
+    }
+}
+impl<P> Clone for ProgressSetter<P> {
+    fn clone(&self) -> Self {
+        Self { future: self.future.clone(), _progress: PhantomData }
+    }
+}
+impl<P> Debug for ProgressSetter<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressSetter").field("future", &"<opaque>").finish()
+    }
+}
+
+/// Creates a new future together with a handle to report its progress of type `P`
+///
+/// # Note
+/// The progress can be observed via [`Getter::progress`] and [`Getter::wait_progress_timeout`] without consuming the
+/// result.
+pub fn with_progress<T, P>() -> (Setter<T>, ProgressSetter<P>, Getter<T>)
+where
+    T: Send + 'static,
+    P: Send + 'static,
+{
+    let (setter, getter) = crate::new();
+    let progress = ProgressSetter { future: setter.reporter(), _progress: PhantomData };
+    (setter, progress, getter)
+}
@@ -0,0 +1,90 @@
+//! Implements a bridge that completes futures from rayon tasks
+
+use crate::future::Getter;
+use rayon::ThreadPool;
+
+/// Wraps `f` into a task that completes the returned getter
+///
+/// # Note
+/// The task skips `f` if the getter has been cancelled before it has been started. A panic inside `f` cancels the
+/// future instead of unwinding into rayon.
+fn task<T, F>(f: F) -> (impl FnOnce() + Send + 'static, Getter<T>)
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (setter, getter) = crate::new();
+    let task = move || {
+        // Skip the computation entirely if the getter has lost interest
+        if !setter.is_cancelled() {
+            setter.complete_with(f);
+        }
+    };
+    (task, getter)
+}
+
+/// Spawns `f` onto the current rayon pool, returns a getter for its result
+///
+/// # Note
+/// The current pool is the pool of the calling worker thread, or the global pool otherwise, see [`rayon::spawn`].
+pub fn spawn<T, F>(f: F) -> Getter<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (task, getter) = task(f);
+    rayon::spawn(task);
+    getter
+}
+/// Spawns `f` onto the current rayon pool in FIFO order, see [`spawn`] and [`rayon::spawn_fifo`]
+pub fn spawn_fifo<T, F>(f: F) -> Getter<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (task, getter) = task(f);
+    rayon::spawn_fifo(task);
+    getter
+}
+
+/// Spawns `f` onto `pool`, returns a getter for its result, see [`spawn`]
+pub fn spawn_in<T, F>(pool: &ThreadPool, f: F) -> Getter<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (task, getter) = task(f);
+    pool.spawn(task);
+    getter
+}
+/// Spawns `f` onto `pool` in FIFO order, see [`spawn_fifo`]
+pub fn spawn_fifo_in<T, F>(pool: &ThreadPool, f: F) -> Getter<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (task, getter) = task(f);
+    pool.spawn_fifo(task);
+    getter
+}
+
+/// Spawns `a` and `b` onto the current rayon pool as a pair that is executed via [`rayon::join`], returns a getter
+/// for each result
+///
+/// # Note
+/// Each side is skipped individually if its getter has been cancelled before it has been started, and a panic on one
+/// side only cancels that side's future.
+pub fn join<A, B, FA, FB>(a: FA, b: FB) -> (Getter<A>, Getter<B>)
+where
+    A: Send + 'static,
+    B: Send + 'static,
+    FA: FnOnce() -> A + Send + 'static,
+    FB: FnOnce() -> B + Send + 'static,
+{
+    let (task_a, getter_a) = task(a);
+    let (task_b, getter_b) = task(b);
+    rayon::spawn(move || {
+        rayon::join(task_a, task_b);
+    });
+    (getter_a, getter_b)
+}
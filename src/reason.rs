@@ -0,0 +1,129 @@
+//! Implements futures that carry a typed cancellation reason
+
+use crate::{
+    error::CancelReason,
+    future::{Getter, Setter},
+    outcome::CancelOutcome,
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Mutex, OnceLock},
+};
+
+/// The cancellation reason that is shared by both halves
+struct Reason<R> {
+    /// Serializes cancellations against reads so that a reason is never observed before or after its cancellation
+    lock: Mutex<()>,
+    /// The reason if any
+    reason: OnceLock<R>,
+}
+impl<R> Reason<R> {
+    /// Cancels the future via `cancel` and records `reason` if the future has been cancelled by this call
+    fn cancel_with<F>(&self, reason: R, cancel: F)
+    where
+        F: FnOnce() -> bool,
+    {
+        let _lock = self.lock.lock().expect("The reason is poisoned?!");
+        if cancel() {
+            let _ = self.reason.set(reason);
+        }
+    }
+    /// The reason if the future has been cancelled explicitly with a reason
+    fn get(&self) -> Option<&R> {
+        let _lock = self.lock.lock().expect("The reason is poisoned?!");
+        self.reason.get()
+    }
+}
+impl<R> Debug for Reason<R>
+where
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reason").field("lock", &"<opaque>").field("reason", &self.reason.get()).finish()
+    }
+}
+
+/// A setter for a future that carries a typed cancellation reason
+pub struct ReasonSetter<T, R> {
+    /// The underlying setter
+    setter: Setter<T>,
+    /// The shared cancellation reason
+    reason: Arc<Reason<R>>,
+}
+impl<T, R> ReasonSetter<T, R> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.setter.is_cancelled()
+    }
+    /// Cancels the future with `reason` unless it has been resolved already
+    pub fn cancel_with(&self, reason: R) {
+        self.reason.cancel_with(reason, || self.setter.try_cancel() == CancelOutcome::Cancelled);
+    }
+    /// The reason if the future has been cancelled explicitly with a reason
+    pub fn cancellation_reason(&self) -> Option<&R> {
+        self.reason.get()
+    }
+
+    /// Sets the result
+    pub fn set(self, value: T) {
+        self.setter.set(value);
+    }
+}
+impl<T, R> Debug for ReasonSetter<T, R>
+where
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReasonSetter").field("setter", &self.setter).field("reason", &self.reason).finish()
+    }
+}
+
+/// A getter for a future that carries a typed cancellation reason
+pub struct ReasonGetter<T, R> {
+    /// The underlying getter
+    getter: Getter<T>,
+    /// The shared cancellation reason
+    reason: Arc<Reason<R>>,
+}
+impl<T, R> ReasonGetter<T, R> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.getter.is_cancelled()
+    }
+    /// Cancels the future with `reason` unless it has been resolved already
+    pub fn cancel_with(&self, reason: R) {
+        self.reason.cancel_with(reason, || self.getter.try_cancel() == CancelOutcome::Cancelled);
+    }
+    /// The reason if the future has been cancelled explicitly with a reason
+    pub fn cancellation_reason(&self) -> Option<&R> {
+        self.reason.get()
+    }
+
+    /// Waits until the result is ready, returns either `Ok(result)` if the future has completed successfully or the
+    /// reason why the future has been cancelled
+    pub fn wait(self) -> Result<T, CancelReason<R>>
+    where
+        R: Clone,
+    {
+        let reason = self.reason.clone();
+        match self.getter.wait() {
+            Some(value) => Ok(value),
+            None => Err(reason.get().cloned().map_or(CancelReason::Dropped, CancelReason::Reason)),
+        }
+    }
+}
+impl<T, R> Debug for ReasonGetter<T, R>
+where
+    R: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReasonGetter").field("getter", &self.getter).field("reason", &self.reason).finish()
+    }
+}
+
+/// Creates a new future whose cancellation carries a typed reason
+pub fn with_reason<T, R>() -> (ReasonSetter<T, R>, ReasonGetter<T, R>) {
+    let (setter, getter) = crate::new();
+    let reason = Arc::new(Reason { lock: Mutex::new(()), reason: OnceLock::new() });
+    (ReasonSetter { setter, reason: reason.clone() }, ReasonGetter { getter, reason })
+}
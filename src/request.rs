@@ -0,0 +1,82 @@
+//! Implements request-reply pairs where the reply setter travels alongside the request
+
+use crate::future::{Getter, Setter};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::mpsc::{self, Receiver, Sender},
+    time::Duration,
+};
+
+/// A request together with the setter for its reply
+type Envelope<Req, Resp> = (Req, Setter<Resp>);
+
+/// The requesting half of a request-reply pair, see [`request`]
+///
+/// # Note
+/// Clones of the requester send to the same responder.
+pub struct Requester<Req, Resp> {
+    /// The request queue
+    queue: Sender<Envelope<Req, Resp>>,
+}
+impl<Req, Resp> Requester<Req, Resp> {
+    /// Sends a request, returns a getter for the reply
+    ///
+    /// # Note
+    /// If the responder has been dropped, the returned getter is cancelled immediately.
+    pub fn send(&self, request: Req) -> Getter<Resp> {
+        // A failed send drops the reply setter and thus cancels the reply
+        let (setter, getter) = crate::new();
+        let _ = self.queue.send((request, setter));
+        getter
+    }
+}
+impl<Req, Resp> Clone for Requester<Req, Resp> {
+    fn clone(&self) -> Self {
+        Self { queue: self.queue.clone() }
+    }
+}
+impl<Req, Resp> Debug for Requester<Req, Resp> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Requester").field("queue", &self.queue).finish()
+    }
+}
+
+/// The responding half of a request-reply pair, see [`request`]
+///
+/// # Note
+/// Dropping the responder cancels the replies of all requests that have not been received yet.
+pub struct Responder<Req, Resp> {
+    /// The request queue
+    queue: Receiver<Envelope<Req, Resp>>,
+}
+impl<Req, Resp> Responder<Req, Resp> {
+    /// Blocks until a request arrives, returns either the request and the setter for its reply, or `None` if all
+    /// requesters have been dropped
+    pub fn recv(&self) -> Option<(Req, Setter<Resp>)> {
+        self.queue.recv().ok()
+    }
+    /// Blocks until a request arrives or the timeout is reached, returns either the request and the setter for its
+    /// reply, or `None` if the timeout has been reached or all requesters have been dropped
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<(Req, Setter<Resp>)> {
+        self.queue.recv_timeout(timeout).ok()
+    }
+    /// Receives a request without blocking if one is available
+    pub fn try_recv(&self) -> Option<(Req, Setter<Resp>)> {
+        self.queue.try_recv().ok()
+    }
+}
+impl<Req, Resp> Debug for Responder<Req, Resp> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Responder").field("queue", &self.queue).finish()
+    }
+}
+
+/// Creates a new request-reply pair
+///
+/// # Note
+/// Every request carries the setter for its reply, so cancelling the reply getter is visible to the responder via
+/// [`Setter::is_cancelled`].
+pub fn request<Req, Resp>() -> (Requester<Req, Resp>, Responder<Req, Resp>) {
+    let (sender, receiver) = mpsc::channel();
+    (Requester { queue: sender }, Responder { queue: receiver })
+}
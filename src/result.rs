@@ -0,0 +1,108 @@
+//! Implements helpers for futures that carry a `Result`
+
+use crate::{
+    error::FutureError,
+    future::{Getter, Setter},
+};
+use std::{
+    fmt::{self, Debug, Formatter},
+    time::Duration,
+};
+
+impl<T, E> Setter<Result<T, E>> {
+    /// Sets the result to `Ok(value)`
+    pub fn set_ok(self, value: T) {
+        self.set(Ok(value));
+    }
+    /// Sets the result to `Err(error)`
+    pub fn set_err(self, error: E) {
+        self.set(Err(error));
+    }
+}
+
+impl<T, E> Getter<Result<T, E>> {
+    /// Waits until the result is ready, returns either the value if the future has completed with `Ok`, or an error that
+    /// covers both cancellation and `Err`
+    pub fn wait_ok(self) -> Result<T, FutureError<E>> {
+        match self.wait_result() {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(error)) => Err(FutureError::Failed(error)),
+            Err(reason) => Err(FutureError::Cancelled(reason)),
+        }
+    }
+    /// Waits until the result is ready or the timeout is reached, returns either the outcome like [`Getter::wait_ok`]
+    /// or `Err(self)` if the timeout has been reached
+    pub fn wait_ok_timeout(self, timeout: Duration) -> Result<Result<T, FutureError<E>>, Self> {
+        match self.wait_timeout_result(timeout)? {
+            Ok(result) => Ok(result.map_err(FutureError::Failed)),
+            Err(reason) => Ok(Err(FutureError::Cancelled(reason))),
+        }
+    }
+}
+
+/// A setter for a future that completes with either a value or an error, see [`crate::new_result`]
+pub struct ResultSetter<T, E> {
+    /// The underlying setter
+    setter: Setter<Result<T, E>>,
+}
+impl<T, E> ResultSetter<T, E> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.setter.is_cancelled()
+    }
+    /// Cancels the future
+    pub fn cancel(self) {
+        self.setter.cancel();
+    }
+
+    /// Completes the future with `value`
+    pub fn ok(self, value: T) {
+        self.setter.set_ok(value);
+    }
+    /// Completes the future with `error`
+    pub fn err(self, error: E) {
+        self.setter.set_err(error);
+    }
+}
+impl<T, E> Debug for ResultSetter<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultSetter").field("setter", &self.setter).finish()
+    }
+}
+
+/// A getter for a future that completes with either a value or an error, see [`crate::new_result`]
+pub struct ResultGetter<T, E> {
+    /// The underlying getter
+    getter: Getter<Result<T, E>>,
+}
+impl<T, E> ResultGetter<T, E> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.getter.is_cancelled()
+    }
+    /// Cancels the future
+    pub fn cancel(&self) {
+        self.getter.cancel();
+    }
+
+    /// Waits until the result is ready, returns either the value or an error that covers both cancellation and failure
+    pub fn wait(self) -> Result<T, FutureError<E>> {
+        self.getter.wait_ok()
+    }
+    /// Waits until the result is ready or the timeout is reached, returns either the outcome like
+    /// [`ResultGetter::wait`] or `Err(self)` if the timeout has been reached
+    pub fn wait_timeout(self, timeout: Duration) -> Result<Result<T, FutureError<E>>, Self> {
+        self.getter.wait_ok_timeout(timeout).map_err(|getter| Self { getter })
+    }
+}
+impl<T, E> Debug for ResultGetter<T, E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultGetter").field("getter", &self.getter).finish()
+    }
+}
+
+/// Creates a new future that completes with either a value or an error
+pub fn new_result<T, E>() -> (ResultSetter<T, E>, ResultGetter<T, E>) {
+    let (setter, getter) = crate::new();
+    (ResultSetter { setter }, ResultGetter { getter })
+}
@@ -0,0 +1,159 @@
+//! Implements cloneable getters that broadcast the result to every waiter
+
+use crate::{error::Cancelled, future::Getter};
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Mutex, MutexGuard, OnceLock, TryLockError},
+    time::Duration,
+};
+
+/// A cloneable getter that hands a clone of the result to every waiter
+///
+/// # Note
+/// Dropping a single clone does not cancel the future; the future is only cancelled if the last clone is dropped.
+pub struct SharedGetter<T> {
+    /// The underlying getter
+    getter: Arc<Getter<T>>,
+}
+impl<T> SharedGetter<T>
+where
+    T: Clone,
+{
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.getter.is_cancelled()
+    }
+
+    /// Waits until the result is ready, returns either a clone of the result if the future has completed successfully
+    /// or `None` if the future has been cancelled
+    pub fn wait(&self) -> Option<T> {
+        self.getter.wait_ref().map(|result| result.clone())
+    }
+}
+impl<T> Clone for SharedGetter<T> {
+    fn clone(&self) -> Self {
+        Self { getter: self.getter.clone() }
+    }
+}
+impl<T> Debug for SharedGetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedGetter").field("getter", &self.getter).finish()
+    }
+}
+
+impl<T> Getter<T>
+where
+    T: Clone,
+{
+    /// Converts the getter into a cloneable getter that hands a clone of the result to every waiter
+    pub fn shared(self) -> SharedGetter<T> {
+        SharedGetter { getter: Arc::new(self) }
+    }
+}
+
+/// The shared state behind a shared future
+struct Inner<T> {
+    /// The underlying getter until the result has been claimed
+    getter: Mutex<Option<Getter<T>>>,
+    /// The claimed result
+    result: OnceLock<Result<T, Cancelled>>,
+}
+
+/// A cloneable handle that lends a reference to the result to every reader
+///
+/// # Note
+/// The result is claimed once and then stored alongside the handles, so `T` does not need to be `Clone`; it lives as
+/// long as any handle does. Dropping a single handle does not cancel the future; the future is only cancelled if the
+/// last handle is dropped before the result has been claimed.
+pub struct SharedFuture<T> {
+    /// The shared state
+    inner: Arc<Inner<T>>,
+}
+impl<T> SharedFuture<T> {
+    /// Whether the future has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        // Check the claimed result first, then fall back to the getter unless another handle is claiming it
+        if let Some(result) = self.inner.result.get() {
+            return result.is_err();
+        }
+        match self.inner.getter.try_lock() {
+            Ok(getter) => match getter.as_ref() {
+                Some(getter) => getter.is_cancelled(),
+                None => self.inner.result.get().is_some_and(Result::is_err),
+            },
+            Err(TryLockError::WouldBlock) => false,
+            Err(TryLockError::Poisoned(_)) => panic!("The getter is poisoned?!"),
+        }
+    }
+
+    /// Returns a reference to the result if the future has completed successfully, or `None` if the future is still
+    /// pending or has been cancelled
+    ///
+    /// # Note
+    /// This function never blocks; if another handle is currently claiming the result, `None` is returned. A deferred
+    /// computation is not evaluated, see [`crate::lazy`].
+    pub fn get(&self) -> Option<&T> {
+        // Try to claim the result without waiting
+        let getter = match self.inner.getter.try_lock() {
+            Ok(getter) => getter,
+            Err(TryLockError::WouldBlock) => return self.inner.result.get()?.as_ref().ok(),
+            Err(TryLockError::Poisoned(_)) => panic!("The getter is poisoned?!"),
+        };
+        if getter.as_ref().is_some_and(Getter::is_deferred) {
+            return None;
+        }
+        self.claim(getter, Some(Duration::ZERO));
+        self.inner.result.get()?.as_ref().ok()
+    }
+
+    /// Waits until the result is ready and returns a reference to it, or the reason why the future has been cancelled
+    pub fn wait(&self) -> Result<&T, Cancelled> {
+        // Claim the result if necessary
+        if self.inner.result.get().is_none() {
+            let getter = self.inner.getter.lock().expect("The getter is poisoned?!");
+            self.claim(getter, None);
+        }
+
+        // Borrow the claimed result
+        let result = self.inner.result.get().expect("The result has not been claimed?!");
+        result.as_ref().map_err(|reason| *reason)
+    }
+
+    /// Claims the result from the getter and stores it, waiting at most for `timeout` if given
+    fn claim(&self, mut getter: MutexGuard<'_, Option<Getter<T>>>, timeout: Option<Duration>) {
+        // Take the getter if the result has not been claimed yet
+        let Some(pending) = getter.take() else {
+            return;
+        };
+
+        // Wait for the result and store it, or put the getter back on timeout
+        let result = match timeout {
+            Some(timeout) => pending.wait_timeout_result(timeout),
+            None => Ok(pending.wait_result()),
+        };
+        match result {
+            Ok(result) => {
+                let _ = self.inner.result.set(result);
+            }
+            Err(pending) => *getter = Some(pending),
+        }
+    }
+}
+impl<T> Clone for SharedFuture<T> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+impl<T> Debug for SharedFuture<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedFuture").field("claimed", &self.inner.result.get().is_some()).finish()
+    }
+}
+
+impl<T> Getter<T> {
+    /// Converts the getter into a cloneable handle that lends a reference to the result to every reader
+    pub fn shared_future(self) -> SharedFuture<T> {
+        let inner = Inner { getter: Mutex::new(Some(self)), result: OnceLock::new() };
+        SharedFuture { inner: Arc::new(inner) }
+    }
+}
@@ -0,0 +1,42 @@
+//! Implements a shared signal to wait on multiple futures at once
+
+use std::{
+    mem,
+    sync::{Condvar, Mutex},
+    time::Instant,
+};
+
+/// A shared signal that wakes a thread which waits on multiple futures at once
+///
+/// # Note
+/// The signal is sticky: a notification that arrives while nobody is waiting is consumed by the next wait.
+#[derive(Debug, Default)]
+pub(in crate) struct Signal {
+    /// Whether the signal has been notified since the last wait
+    notified: Mutex<bool>,
+    /// The condition variable
+    condvar: Condvar,
+}
+impl Signal {
+    /// Notifies the signal
+    pub fn notify(&self) {
+        let mut notified = self.notified.lock().expect("The signal is poisoned?!");
+        *notified = true;
+        self.condvar.notify_all();
+    }
+    /// Waits until the signal has been notified and resets it
+    pub fn wait(&self) {
+        let notified = self.notified.lock().expect("The signal is poisoned?!");
+        let mut notified = self.condvar.wait_while(notified, |notified| !*notified).expect("The signal is poisoned?!");
+        *notified = false;
+    }
+    /// Waits until the signal has been notified or the deadline is reached and resets it, returns whether the signal has
+    /// been notified
+    pub fn wait_deadline(&self, deadline: Instant) -> bool {
+        let timeout = deadline.saturating_duration_since(Instant::now());
+        let notified = self.notified.lock().expect("The signal is poisoned?!");
+        let (mut notified, _) =
+            self.condvar.wait_timeout_while(notified, timeout, |notified| !*notified).expect("The signal is poisoned?!");
+        mem::replace(&mut *notified, false)
+    }
+}
@@ -0,0 +1,130 @@
+//! Implements a reusable multi-shot slot to hand values from one thread to another
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+/// The state of a slot
+struct State<T> {
+    /// The value that has not been taken yet if any
+    value: Option<T>,
+    /// Whether the slot has been closed or not
+    closed: bool,
+}
+
+/// The inner slot
+struct Slot<T> {
+    /// The state
+    state: Mutex<State<T>>,
+    /// The signal variable
+    signal: Condvar,
+}
+impl<T> Slot<T> {
+    /// Closes the slot and wakes waiting threads
+    fn close(&self) {
+        let mut state = self.state.lock().expect("The slot is poisoned?!");
+        state.closed = true;
+        self.signal.notify_all();
+    }
+    /// Whether the slot has been closed or not
+    fn is_closed(&self) -> bool {
+        self.state.lock().expect("The slot is poisoned?!").closed
+    }
+}
+
+/// The producing half of a slot, see [`slot`]
+pub struct SlotSetter<T> {
+    /// The shared slot
+    slot: Arc<Slot<T>>,
+}
+impl<T> SlotSetter<T> {
+    /// Puts a value into the slot and wakes the getter, returns either `Ok(displaced)` with a previous value that has not
+    /// been taken yet, or `Err(value)` if the slot has been closed
+    pub fn put(&self, value: T) -> Result<Option<T>, T> {
+        let mut state = self.slot.state.lock().expect("The slot is poisoned?!");
+        if state.closed {
+            return Err(value);
+        }
+
+        // Replace the value and wake the getter
+        let displaced = state.value.replace(value);
+        self.slot.signal.notify_all();
+        Ok(displaced)
+    }
+    /// Whether the slot has been closed or not
+    pub fn is_closed(&self) -> bool {
+        self.slot.is_closed()
+    }
+    /// Closes the slot for both sides
+    pub fn close(&self) {
+        self.slot.close();
+    }
+}
+impl<T> Debug for SlotSetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlotSetter").field("closed", &self.is_closed()).finish()
+    }
+}
+impl<T> Drop for SlotSetter<T> {
+    fn drop(&mut self) {
+        self.slot.close();
+    }
+}
+
+/// The consuming half of a slot, see [`slot`]
+pub struct SlotGetter<T> {
+    /// The shared slot
+    slot: Arc<Slot<T>>,
+}
+impl<T> SlotGetter<T> {
+    /// Waits until a value is available and takes it, returns either `Some(value)` or `None` if the slot has been closed
+    ///
+    /// # Note
+    /// A value that has been put before the slot has been closed can still be taken.
+    pub fn take(&self) -> Option<T> {
+        let state = self.slot.state.lock().expect("The slot is poisoned?!");
+        let mut state = (self.slot.signal)
+            .wait_while(state, |state| state.value.is_none() && !state.closed)
+            .expect("The slot is poisoned?!");
+        state.value.take()
+    }
+    /// Waits until a value is available or the timeout is reached and takes it, returns either `Some(value)` or `None` if
+    /// the timeout has been reached or the slot has been closed
+    pub fn take_timeout(&self, timeout: Duration) -> Option<T> {
+        let state = self.slot.state.lock().expect("The slot is poisoned?!");
+        let (mut state, _) = (self.slot.signal)
+            .wait_timeout_while(state, timeout, |state| state.value.is_none() && !state.closed)
+            .expect("The slot is poisoned?!");
+        state.value.take()
+    }
+    /// Whether the slot has been closed or not
+    pub fn is_closed(&self) -> bool {
+        self.slot.is_closed()
+    }
+    /// Closes the slot for both sides
+    pub fn close(&self) {
+        self.slot.close();
+    }
+}
+impl<T> Debug for SlotGetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlotGetter").field("closed", &self.is_closed()).finish()
+    }
+}
+impl<T> Drop for SlotGetter<T> {
+    fn drop(&mut self) {
+        self.slot.close();
+    }
+}
+
+/// Creates a new reusable slot
+///
+/// # Note
+/// Unlike a future, the setter can put values repeatedly and the getter can take them repeatedly; every value is taken
+/// at most once. Dropping or closing either half closes the slot for both sides.
+pub fn slot<T>() -> (SlotSetter<T>, SlotGetter<T>) {
+    let slot = Arc::new(Slot { state: Mutex::new(State { value: None, closed: false }), signal: Condvar::new() });
+    (SlotSetter { slot: slot.clone() }, SlotGetter { slot })
+}
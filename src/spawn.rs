@@ -0,0 +1,93 @@
+//! Implements helpers that compute the result of a future on a new thread
+
+use crate::future::Getter;
+use std::{
+    io, panic,
+    thread::{self, Builder, JoinHandle, Scope},
+};
+
+/// Spawns a new thread that computes the result of the future via `f`
+///
+/// # Note
+/// The thread checks whether the future has been cancelled before `f` is invoked, so a getter that is dropped
+/// immediately does not trigger the work. A panic in `f` is delivered to the getter as if [`crate::Setter::complete_with`]
+/// was used.
+pub fn spawn<T, F>(f: F) -> Getter<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (setter, getter) = crate::new();
+    thread::spawn(move || {
+        // Skip the work if nobody is interested in the result anymore
+        if !setter.is_cancelled() {
+            setter.complete_with(f);
+        }
+    });
+    getter
+}
+
+/// Spawns a new scoped thread that computes the result of the future via `f`, see [`spawn`]
+///
+/// # Note
+/// Unlike [`spawn`], `f` and the result may borrow from the enclosing scope of [`thread::scope`].
+pub fn spawn_scoped<'scope, 'env, T, F>(scope: &'scope Scope<'scope, 'env>, f: F) -> Getter<T>
+where
+    F: FnOnce() -> T + Send + 'scope,
+    T: Send + 'scope,
+{
+    let (setter, getter) = crate::new();
+    scope.spawn(move || {
+        // Skip the work if nobody is interested in the result anymore
+        if !setter.is_cancelled() {
+            setter.complete_with(f);
+        }
+    });
+    getter
+}
+
+/// Spawns a new thread configured by `builder` that computes the result of the future via `f`, see [`spawn`]
+///
+/// # Note
+/// If the thread has a name, it is exposed via [`Getter::label`]. If the thread cannot be created, the error is
+/// returned and `f` is dropped without being invoked.
+pub fn spawn_with<T, F>(builder: Builder, f: F) -> io::Result<Getter<T>>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (setter, getter) = crate::new();
+    let handle = builder.spawn(move || {
+        // Skip the work if nobody is interested in the result anymore
+        if !setter.is_cancelled() {
+            setter.complete_with(f);
+        }
+    })?;
+
+    // Label the future with the thread name
+    if let Some(name) = handle.thread().name() {
+        getter.set_label(name.to_string());
+    }
+    Ok(getter)
+}
+
+/// Creates a future that completes with the result of the thread behind `handle`
+///
+/// # Note
+/// The handle is joined on a helper thread; if the thread panics, the payload is delivered to the getter as if
+/// [`crate::Setter::complete_with`] was used. Cancelling the future does not stop the thread, it merely detaches the
+/// getter from it.
+pub fn from_join_handle<T>(handle: JoinHandle<T>) -> Getter<T>
+where
+    T: Send + 'static,
+{
+    let (setter, getter) = crate::new();
+    thread::spawn(move || {
+        // Join the thread and forward a panic as if it happened inside `complete_with`
+        setter.complete_with(|| match handle.join() {
+            Ok(value) => value,
+            Err(payload) => panic::resume_unwind(payload),
+        });
+    });
+    getter
+}
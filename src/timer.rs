@@ -0,0 +1,178 @@
+//! Implements a shared timer thread to schedule delayed actions
+
+use crate::future::Getter;
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{Condvar, Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A scheduled action
+type Action = Box<dyn FnOnce() + Send>;
+
+/// The queue of scheduled actions
+#[derive(Default)]
+struct Queue {
+    /// The scheduled actions ordered by their deadline
+    entries: BTreeMap<(Instant, u64), Action>,
+    /// The next unique entry ID
+    next_id: u64,
+}
+
+/// A shared timer thread that executes scheduled actions
+#[derive(Default)]
+struct Timer {
+    /// The queue of scheduled actions
+    queue: Mutex<Queue>,
+    /// The signal to wake the timer thread if the queue changes
+    signal: Condvar,
+}
+impl Timer {
+    /// The shared timer instance; the timer thread is spawned on first use
+    fn shared() -> &'static Self {
+        static TIMER: OnceLock<Timer> = OnceLock::new();
+        TIMER.get_or_init(|| {
+            // Spawn the timer thread once the instance is initialized
+            thread::Builder::new()
+                .name("tiny_future-timer".to_string())
+                .spawn(|| Self::shared().run())
+                .expect("Failed to spawn timer thread");
+            Self::default()
+        })
+    }
+
+    /// Runs the timer loop
+    fn run(&self) {
+        let mut queue = self.queue.lock().expect("The timer is poisoned?!");
+        loop {
+            // Get the next entry if any
+            let now = Instant::now();
+            let Some((deadline, id)) = queue.entries.keys().next().copied() else {
+                queue = self.signal.wait(queue).expect("The timer is poisoned?!");
+                continue;
+            };
+
+            // Wait until the entry is due
+            if deadline > now {
+                let timeout = deadline.saturating_duration_since(now);
+                (queue, _) = self.signal.wait_timeout(queue, timeout).expect("The timer is poisoned?!");
+                continue;
+            }
+
+            // Execute the action outside of the lock; panics must not tear down the timer thread
+            let action = queue.entries.remove(&(deadline, id));
+            drop(queue);
+            if let Some(action) = action {
+                let _ = panic::catch_unwind(AssertUnwindSafe(action));
+            }
+            queue = self.queue.lock().expect("The timer is poisoned?!");
+        }
+    }
+}
+
+/// A handle to abort a scheduled cancellation, see [`Getter::cancel_after`](crate::Getter::cancel_after)
+#[derive(Debug, Clone)]
+pub struct ScheduledCancel {
+    /// The underlying timer entry
+    entry: TimerEntry,
+}
+impl ScheduledCancel {
+    /// Creates a new handle for the given timer entry
+    pub(in crate) const fn new(entry: TimerEntry) -> Self {
+        Self { entry }
+    }
+
+    /// Aborts the scheduled cancellation, returns whether the cancellation was still pending
+    pub fn abort(&self) -> bool {
+        self.entry.disarm()
+    }
+}
+
+/// A handle to a scheduled action
+#[derive(Debug, Clone, Copy)]
+pub(in crate) struct TimerEntry {
+    /// The key of the entry, or `None` if the deadline is not representable and the action will never be executed
+    key: Option<(Instant, u64)>,
+}
+impl TimerEntry {
+    /// Schedules `action` to be executed on the shared timer thread after `timeout`
+    pub fn after<F>(timeout: Duration, action: F) -> Self
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        match Instant::now().checked_add(timeout) {
+            Some(deadline) => Self::at(deadline, action),
+            None => Self { key: None },
+        }
+    }
+    /// Schedules `action` to be executed on the shared timer thread at `deadline`
+    pub fn at<F>(deadline: Instant, action: F) -> Self
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // Insert the entry
+        let timer = Timer::shared();
+        let mut queue = timer.queue.lock().expect("The timer is poisoned?!");
+        let key = (deadline, queue.next_id);
+        queue.next_id += 1;
+        queue.entries.insert(key, Box::new(action));
+
+        // Wake the timer thread so that it can re-evaluate the next deadline
+        timer.signal.notify_all();
+        Self { key: Some(key) }
+    }
+
+    /// Disarms the entry so that the action is dropped without being executed, returns whether the entry was still
+    /// armed
+    pub fn disarm(&self) -> bool {
+        let Some(key) = self.key else {
+            return false;
+        };
+
+        // Remove the action and drop it outside of the lock
+        let action = Timer::shared().queue.lock().expect("The timer is poisoned?!").entries.remove(&key);
+        action.is_some()
+    }
+}
+
+/// Arms a timer entry via `schedule` that completes the future with the firing time, and disarms the entry if the future
+/// is cancelled before
+fn fire<F>(schedule: F) -> Getter<Instant>
+where
+    F: FnOnce(Box<dyn FnOnce() + Send>) -> TimerEntry,
+{
+    // Schedule a clone of the setter so that the timer slot can be released on cancellation
+    let (setter, getter) = crate::new();
+    let entry = schedule({
+        let setter = setter.clone();
+        Box::new(move || setter.set(Instant::now()))
+    });
+    setter.on_cancelled(move || {
+        entry.disarm();
+    });
+    getter
+}
+
+/// Creates a future that completes with the firing time after `duration`
+///
+/// # Note
+/// All timers share a single timer thread. If the future is cancelled before, the timer is released immediately.
+pub fn after(duration: Duration) -> Getter<Instant> {
+    fire(|action| TimerEntry::after(duration, action))
+}
+
+/// Creates a future that completes with the firing time once `deadline` is reached
+///
+/// # Note
+/// The deadline is measured against the monotonic clock, so changes of the system time neither fire the timer early nor
+/// late. A deadline in the past completes the future immediately; arbitrarily distant deadlines are supported.
+pub fn at(deadline: Instant) -> Getter<Instant> {
+    // Complete the future immediately if the deadline has been reached already
+    let now = Instant::now();
+    if deadline <= now {
+        return crate::ready(now);
+    }
+    fire(|action| TimerEntry::at(deadline, action))
+}
@@ -0,0 +1,57 @@
+//! Implements the optional completion timing of a future
+
+#[cfg(feature = "timing")]
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// The completion timing of a future
+#[derive(Debug)]
+#[cfg(feature = "timing")]
+pub(in crate) struct Timing {
+    /// When the future has been created
+    created_at: Instant,
+    /// When the future has been completed or cancelled
+    completed_at: Mutex<Option<Instant>>,
+}
+#[cfg(feature = "timing")]
+impl Timing {
+    /// Creates a new timing that starts now
+    pub fn new() -> Self {
+        Self { created_at: Instant::now(), completed_at: Mutex::default() }
+    }
+
+    /// Records the completion time if the future has not been completed before
+    pub fn complete(&self) {
+        let mut completed_at = self.completed_at.lock().expect("The timing is poisoned?!");
+        completed_at.get_or_insert_with(Instant::now);
+    }
+
+    /// When the future has been completed or cancelled
+    pub fn completed_at(&self) -> Option<Instant> {
+        *self.completed_at.lock().expect("The timing is poisoned?!")
+    }
+    /// The time between the creation and the completion or cancellation of the future
+    pub fn elapsed(&self) -> Option<Duration> {
+        let completed_at = self.completed_at()?;
+        Some(completed_at.saturating_duration_since(self.created_at))
+    }
+}
+
+/// A no-op completion timing if the `timing` feature is disabled
+#[derive(Debug)]
+#[cfg(not(feature = "timing"))]
+pub(in crate) struct Timing;
+#[cfg(not(feature = "timing"))]
+impl Timing {
+    /// Creates a new no-op timing
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Does nothing
+    pub fn complete(&self) {
+        // No-op
+    }
+}
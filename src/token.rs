@@ -0,0 +1,180 @@
+//! Implements cancellation tokens to cancel many futures at once
+
+use crate::future::{Getter, Setter};
+use std::{
+    fmt::{self, Debug, Formatter},
+    mem,
+    sync::{Arc, Condvar, Mutex, Weak},
+    time::Duration,
+};
+
+/// A type-erased handle to cancel a future that is bound to a token
+pub(in crate) trait Cancel: Send {
+    /// Cancels the future from the getter side
+    fn cancel(&self);
+    /// Whether the future has been resolved or dropped, so that it does not need to be cancelled anymore
+    fn is_resolved(&self) -> bool;
+}
+
+/// The shared state of a token
+#[derive(Default)]
+struct State {
+    /// Whether the token has been cancelled or not
+    cancelled: bool,
+    /// The futures that are bound to the token
+    bound: Vec<Box<dyn Cancel>>,
+}
+
+/// The inner token
+#[derive(Default)]
+struct Inner {
+    /// The state
+    state: Mutex<State>,
+    /// The signal variable
+    signal: Condvar,
+}
+
+/// A cancellation token that cancels every future that is bound to it, see [`crate::with_token`]
+///
+/// # Note
+/// Clones of the token share the same state, so cancelling any clone cancels every bound future and wakes all threads
+/// that are waiting on the token.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    /// The shared state
+    inner: Arc<Inner>,
+}
+impl CancellationToken {
+    /// Creates a new token
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Binds a future to the token, or cancels it immediately if the token has already been cancelled
+    pub(in crate) fn bind(&self, future: Box<dyn Cancel>) {
+        // Prune futures that have been resolved in the meantime so that long-lived tokens do not grow unboundedly
+        let mut state = self.inner.state.lock().expect("The token is poisoned?!");
+        if !state.cancelled {
+            state.bound.retain(|bound| !bound.is_resolved());
+            state.bound.push(future);
+            return;
+        }
+
+        // Cancel the future outside of the lock
+        drop(state);
+        future.cancel();
+    }
+
+    /// Whether the token has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.state.lock().expect("The token is poisoned?!").cancelled
+    }
+    /// Cancels the token and every bound future, and wakes all threads that are waiting on the token
+    pub fn cancel(&self) {
+        // Flag the token as cancelled and take the bound futures
+        let mut state = self.inner.state.lock().expect("The token is poisoned?!");
+        state.cancelled = true;
+        let bound = mem::take(&mut state.bound);
+        self.inner.signal.notify_all();
+        drop(state);
+
+        // Cancel the bound futures outside of the lock
+        for future in bound {
+            future.cancel();
+        }
+    }
+    /// Blocks until the token has been cancelled, or returns immediately if it has been cancelled already
+    pub fn wait_cancelled(&self) {
+        let state = self.inner.state.lock().expect("The token is poisoned?!");
+        let _state = self.inner.signal.wait_while(state, |state| !state.cancelled).expect("The token is poisoned?!");
+    }
+    /// Blocks until the token has been cancelled or the timeout is reached, returns whether the token has been cancelled
+    pub fn wait_cancelled_timeout(&self, timeout: Duration) -> bool {
+        let state = self.inner.state.lock().expect("The token is poisoned?!");
+        let (state, _) = (self.inner.signal)
+            .wait_timeout_while(state, timeout, |state| !state.cancelled)
+            .expect("The token is poisoned?!");
+        state.cancelled
+    }
+}
+impl Cancel for Weak<Inner> {
+    fn cancel(&self) {
+        if let Some(inner) = self.upgrade() {
+            CancellationToken { inner }.cancel();
+        }
+    }
+    fn is_resolved(&self) -> bool {
+        match self.upgrade() {
+            Some(inner) => inner.state.lock().expect("The token is poisoned?!").cancelled,
+            None => true,
+        }
+    }
+}
+impl Debug for CancellationToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (cancelled, bound) = match self.inner.state.lock() {
+            Ok(state) => (Some(state.cancelled), Some(state.bound.len())),
+            Err(_) => (None, None),
+        };
+        f.debug_struct("CancellationToken").field("cancelled", &cancelled).field("bound", &bound).finish()
+    }
+}
+
+/// A hierarchical cancellation scope; cancelling a scope cancels all descendant scopes and their bound futures
+///
+/// # Note
+/// A scope only holds weak references to its children, while each child keeps its parent alive and each pending future
+/// that is bound via [`CancelScope::bind`] keeps its scope alive. So a dropped child scope is released once its futures
+/// are resolved, but still cancels its pending futures if the parent is cancelled. Cancelling a child scope leaves its
+/// parent and siblings untouched.
+#[derive(Clone, Default)]
+pub struct CancelScope {
+    /// The underlying token
+    token: CancellationToken,
+    /// The parent scope if any
+    parent: Option<Arc<CancelScope>>,
+}
+impl CancelScope {
+    /// Creates a new root scope
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Derives a child scope that is cancelled if this scope is cancelled
+    pub fn child(&self) -> Self {
+        let child = Self { token: CancellationToken::new(), parent: Some(Arc::new(self.clone())) };
+        self.token.bind(Box::new(Arc::downgrade(&child.token.inner)));
+        child
+    }
+    /// Creates a new future that is bound to the scope, see [`crate::with_token`]
+    ///
+    /// # Note
+    /// The future keeps the scope alive until it is resolved.
+    pub fn bind<T>(&self) -> (Setter<T>, Getter<T>)
+    where
+        T: Send + 'static,
+    {
+        // Release the scope once the future is set or cancelled
+        let (setter, getter) = crate::with_token(&self.token);
+        let scope = self.clone();
+        setter.on_cancelled(move || drop(scope));
+        (setter, getter)
+    }
+
+    /// The underlying token of the scope
+    pub fn token(&self) -> &CancellationToken {
+        &self.token
+    }
+    /// Whether the scope has been cancelled or not
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+    /// Cancels the scope, all descendant scopes and their bound futures
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+}
+impl Debug for CancelScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelScope").field("token", &self.token).field("parent", &self.parent).finish()
+    }
+}
@@ -0,0 +1,57 @@
+//! Implements bridges between futures and `tokio::sync::oneshot` channels
+
+use crate::{bridge::OneshotSender, future::Getter};
+use std::task::{Context, Poll};
+use tokio::sync::oneshot::{self, Receiver, Sender};
+
+impl<T> OneshotSender for Sender<T>
+where
+    T: Send,
+{
+    type Value = T;
+
+    fn poll_closed(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        Sender::poll_closed(self, cx)
+    }
+    fn send(self, value: Self::Value) {
+        // The receiver may be gone already, in which case the value is simply dropped
+        let _ = Sender::send(self, value);
+    }
+}
+
+/// Creates a getter that resolves to the value received from `receiver`
+///
+/// # Note
+/// No thread or runtime is involved: the future is completed on the thread that sends the value, and cancelled if the
+/// sender is dropped without sending a value. If the getter is dropped or cancelled, the receiver is dropped, so the
+/// sender observes the channel as closed.
+pub fn from_tokio<T>(receiver: Receiver<T>) -> Getter<T>
+where
+    T: Send + 'static,
+{
+    crate::bridge::receive(receiver)
+}
+
+impl<T> Getter<T>
+where
+    T: Send + 'static,
+{
+    /// Converts the getter into a tokio receiver
+    ///
+    /// # Note
+    /// No thread or runtime is involved: the result is sent on the thread that completes the future, and the channel is
+    /// closed if the future is cancelled. If the receiver is dropped or closed, the future is cancelled.
+    pub fn into_tokio(self) -> Receiver<T> {
+        let (sender, receiver) = oneshot::channel();
+        crate::bridge::forward(self, sender);
+        receiver
+    }
+}
+impl<T> From<Getter<T>> for Receiver<T>
+where
+    T: Send + 'static,
+{
+    fn from(getter: Getter<T>) -> Self {
+        getter.into_tokio()
+    }
+}
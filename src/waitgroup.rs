@@ -0,0 +1,100 @@
+//! Implements a wait group to wait until a number of tasks are done
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{Arc, Condvar, Mutex},
+    time::Duration,
+};
+
+/// The inner wait group
+#[derive(Default)]
+struct Inner {
+    /// The amount of tasks that are not done yet
+    count: Mutex<usize>,
+    /// The signal variable
+    signal: Condvar,
+}
+
+/// A wait group to wait until a number of tasks are done
+///
+/// # Note
+/// Clones of the wait group share the same count. Tasks can either be registered via [`WaitGroup::add`] and completed
+/// via [`WaitGroup::done`], or be tracked via a [`WaitGroupGuard`] that completes the task on drop.
+#[derive(Clone, Default)]
+pub struct WaitGroup {
+    /// The shared state
+    inner: Arc<Inner>,
+}
+impl WaitGroup {
+    /// Creates a new wait group without pending tasks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `n` additional tasks
+    pub fn add(&self, n: usize) {
+        let mut count = self.inner.count.lock().expect("The wait group is poisoned?!");
+        *count = count.checked_add(n).expect("Too many tasks in the wait group?!");
+    }
+    /// Marks a task as done
+    ///
+    /// # Panics
+    /// This function panics if no task is pending.
+    pub fn done(&self) {
+        let mut count = self.inner.count.lock().expect("The wait group is poisoned?!");
+        *count = count.checked_sub(1).expect("No task is pending?!");
+        if *count == 0 {
+            self.inner.signal.notify_all();
+        }
+    }
+    /// Registers an additional task that is done once the returned guard is dropped
+    pub fn guard(&self) -> WaitGroupGuard {
+        self.add(1);
+        WaitGroupGuard { group: self.clone() }
+    }
+    /// The amount of tasks that are not done yet
+    pub fn count(&self) -> usize {
+        *self.inner.count.lock().expect("The wait group is poisoned?!")
+    }
+
+    /// Blocks until all tasks are done
+    pub fn wait(&self) {
+        let count = self.inner.count.lock().expect("The wait group is poisoned?!");
+        let _count = self.inner.signal.wait_while(count, |count| *count > 0).expect("The wait group is poisoned?!");
+    }
+    /// Blocks until all tasks are done or the timeout is reached, returns whether all tasks are done
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let count = self.inner.count.lock().expect("The wait group is poisoned?!");
+        let (count, _) = (self.inner.signal)
+            .wait_timeout_while(count, timeout, |count| *count > 0)
+            .expect("The wait group is poisoned?!");
+        *count == 0
+    }
+}
+impl Debug for WaitGroup {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let count = self.inner.count.lock().ok().map(|count| *count);
+        f.debug_struct("WaitGroup").field("count", &count).finish()
+    }
+}
+
+/// A guard that tracks a task of a [`WaitGroup`] and marks it as done on drop
+///
+/// # Note
+/// The task is also marked as done if the guard is dropped while unwinding from a panic. Cloning the guard registers an
+/// additional task.
+#[derive(Debug)]
+pub struct WaitGroupGuard {
+    /// The associated wait group
+    group: WaitGroup,
+}
+impl Clone for WaitGroupGuard {
+    fn clone(&self) -> Self {
+        self.group.guard()
+    }
+}
+impl Drop for WaitGroupGuard {
+    fn drop(&mut self) {
+        self.group.done();
+    }
+}
@@ -0,0 +1,147 @@
+//! Implements a watch channel that publishes the latest value to many getters
+
+use crate::outcome::WatchOutcome;
+use std::{
+    fmt::{self, Debug, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering::SeqCst},
+        Arc, Condvar, Mutex, MutexGuard,
+    },
+    time::Duration,
+};
+
+/// The state of a watch channel
+struct State<T> {
+    /// The latest value
+    value: T,
+    /// The version of the latest value, incremented on every publish
+    version: u64,
+    /// Whether the setter has been closed or not
+    closed: bool,
+}
+
+/// The inner watch channel
+struct Watch<T> {
+    /// The state
+    state: Mutex<State<T>>,
+    /// The signal variable
+    signal: Condvar,
+}
+impl<T> Watch<T> {
+    /// Closes the channel and wakes all waiting getters
+    fn close(&self) {
+        let mut state = self.state.lock().expect("The watch is poisoned?!");
+        state.closed = true;
+        self.signal.notify_all();
+    }
+}
+
+/// The publishing half of a watch channel, see [`watch`]
+pub struct WatchSetter<T> {
+    /// The shared channel
+    watch: Arc<Watch<T>>,
+}
+impl<T> WatchSetter<T> {
+    /// Publishes a new value and wakes all getters that are waiting for a change
+    pub fn publish(&self, value: T) {
+        let mut state = self.watch.state.lock().expect("The watch is poisoned?!");
+        state.value = value;
+        state.version += 1;
+        self.watch.signal.notify_all();
+    }
+    /// Closes the channel and wakes all waiting getters, see [`WatchOutcome::Closed`]
+    pub fn close(&self) {
+        self.watch.close();
+    }
+}
+impl<T> Debug for WatchSetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchSetter").field("watch", &"<opaque>").finish()
+    }
+}
+impl<T> Drop for WatchSetter<T> {
+    fn drop(&mut self) {
+        self.watch.close();
+    }
+}
+
+/// The observing half of a watch channel, see [`watch`]
+///
+/// # Note
+/// Every clone tracks the version it has seen last on its own.
+pub struct WatchGetter<T> {
+    /// The shared channel
+    watch: Arc<Watch<T>>,
+    /// The version this getter has seen last
+    seen: AtomicU64,
+}
+impl<T> WatchGetter<T> {
+    /// The latest value; marks the latest version as seen
+    pub fn latest(&self) -> T
+    where
+        T: Clone,
+    {
+        let state = self.watch.state.lock().expect("The watch is poisoned?!");
+        self.seen.store(state.version, SeqCst);
+        state.value.clone()
+    }
+    /// Whether the setter has been closed or not
+    pub fn is_closed(&self) -> bool {
+        self.watch.state.lock().expect("The watch is poisoned?!").closed
+    }
+
+    /// Blocks until a value has been published since the last seen version or the setter has been closed
+    ///
+    /// # Note
+    /// This never returns [`WatchOutcome::TimedOut`]. A change that has been published before the setter has been
+    /// closed is reported as [`WatchOutcome::Changed`].
+    pub fn wait_changed(&self) -> WatchOutcome {
+        let state = self.watch.state.lock().expect("The watch is poisoned?!");
+        let seen = self.seen.load(SeqCst);
+        let state = (self.watch.signal)
+            .wait_while(state, |state| state.version == seen && !state.closed)
+            .expect("The watch is poisoned?!");
+        self.observe(state, seen)
+    }
+    /// Blocks until a value has been published since the last seen version, the setter has been closed, or the timeout
+    /// is reached
+    pub fn wait_changed_timeout(&self, timeout: Duration) -> WatchOutcome {
+        let state = self.watch.state.lock().expect("The watch is poisoned?!");
+        let seen = self.seen.load(SeqCst);
+        let (state, _) = (self.watch.signal)
+            .wait_timeout_while(state, timeout, |state| state.version == seen && !state.closed)
+            .expect("The watch is poisoned?!");
+        self.observe(state, seen)
+    }
+    /// Marks the current version as seen and reports how the wait has ended
+    fn observe(&self, state: MutexGuard<State<T>>, seen: u64) -> WatchOutcome {
+        if state.version != seen {
+            self.seen.store(state.version, SeqCst);
+            return WatchOutcome::Changed;
+        }
+        if state.closed {
+            return WatchOutcome::Closed;
+        }
+        WatchOutcome::TimedOut
+    }
+}
+impl<T> Clone for WatchGetter<T> {
+    fn clone(&self) -> Self {
+        Self { watch: self.watch.clone(), seen: AtomicU64::new(self.seen.load(SeqCst)) }
+    }
+}
+impl<T> Debug for WatchGetter<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WatchGetter").field("watch", &"<opaque>").field("seen", &self.seen.load(SeqCst)).finish()
+    }
+}
+
+/// Creates a new watch channel that initially holds `initial`
+///
+/// # Note
+/// The initial value counts as seen, so [`WatchGetter::wait_changed`] blocks until the first publish.
+pub fn watch<T>(initial: T) -> (WatchSetter<T>, WatchGetter<T>) {
+    let state = State { value: initial, version: 0, closed: false };
+    let watch = Arc::new(Watch { state: Mutex::new(state), signal: Condvar::new() });
+    (WatchSetter { watch: watch.clone() }, WatchGetter { watch, seen: AtomicU64::new(0) })
+}
@@ -0,0 +1,42 @@
+//! Implements a waitable Win32 event that is set once a future is resolved
+
+use std::{
+    io,
+    os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle, RawHandle},
+    ptr,
+};
+use windows_sys::Win32::System::Threading::{CreateEventW, SetEvent};
+
+/// A manual-reset event that is set once it has been signalled
+///
+/// # Note
+/// The event is never reset, so it stays signalled once it has been set. The handle is closed if the event is dropped.
+pub(in crate) struct Win32Event {
+    /// The event handle
+    handle: OwnedHandle,
+}
+impl Win32Event {
+    /// Creates a new, non-signalled event
+    pub fn new() -> io::Result<Self> {
+        // Create an anonymous manual-reset event and take ownership of the handle
+        // SAFETY: Null is valid for both the security attributes and the name of an anonymous event
+        let handle = unsafe { CreateEventW(ptr::null(), 1, 0, ptr::null()) };
+        if handle.is_null() {
+            return Err(io::Error::last_os_error());
+        }
+        // SAFETY: `handle` is a valid handle that has just been created and is not owned by anything else
+        let handle = unsafe { OwnedHandle::from_raw_handle(handle) };
+        Ok(Self { handle })
+    }
+
+    /// Sets the event
+    pub fn signal(&self) {
+        // Setting an event only fails for an invalid handle, which cannot happen for an owned handle
+        // SAFETY: The handle is owned by the event and stays open while it is borrowed
+        unsafe { SetEvent(self.handle.as_raw_handle()) };
+    }
+    /// The raw event handle
+    pub fn as_raw_handle(&self) -> RawHandle {
+        self.handle.as_raw_handle()
+    }
+}
@@ -0,0 +1,35 @@
+use std::{thread, time::Duration};
+use tiny_future::AnyGetter;
+
+#[test]
+fn any_getter() {
+    let (setter_a, getter_a) = tiny_future::new::<u8>();
+    let (setter_b, getter_b) = tiny_future::new::<String>();
+    let getters: Vec<Box<dyn AnyGetter + Send>> = vec![getter_a.boxed(), getter_b.boxed()];
+
+    // Resolve the futures after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter_a.set(7);
+        drop(setter_b);
+    });
+
+    // Wait for completion through the erased handles
+    for getter in getters.iter() {
+        getter.wait_done();
+    }
+    assert!(getters[0].is_ready(), "Future is not ready");
+    assert!(getters[1].is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn any_getter_cancel() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let getters: Vec<_> = getters.into_iter().map(|getter| getter.boxed()).collect();
+
+    // Cancel all futures through the erased handles
+    let pending = getters.iter().filter(|getter| !getter.is_ready() && !getter.is_cancelled()).count();
+    assert_eq!(pending, 3, "Futures are not pending");
+    getters.iter().for_each(|getter| getter.cancel());
+    assert!(setters.iter().all(|setter| setter.is_cancelled()), "Futures have not been cancelled");
+}
@@ -0,0 +1,240 @@
+#![cfg(feature = "async")]
+
+use futures::{stream::FuturesUnordered, FutureExt, StreamExt};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering::SeqCst},
+        Arc,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+use tiny_future::Getter;
+
+/// A waker that counts its wakeups
+#[derive(Default)]
+struct CountingWaker {
+    /// The amount of wakeups
+    wakeups: AtomicUsize,
+}
+impl Wake for CountingWaker {
+    fn wake(self: Arc<Self>) {
+        self.wakeups.fetch_add(1, SeqCst);
+    }
+}
+
+/// A waker that unparks a thread
+struct ThreadWaker(Thread);
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drives a future to completion on the current thread
+fn block_on<F>(mut future: F) -> F::Output
+where
+    F: Future + Unpin,
+{
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match Pin::new(&mut future).poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn poll_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut future = getter.into_async();
+    let waker = Arc::new(CountingWaker::default());
+    let task = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&task);
+
+    // The future must be pending until the result is set, which must wake the task
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending, "Future is not pending");
+    setter.set(7);
+    assert_eq!(waker.wakeups.load(SeqCst), 1, "Task has not been woken");
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(Some(7)), "Future has invalid result");
+}
+
+#[test]
+fn poll_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut future = getter.into_async();
+    let waker = Arc::new(CountingWaker::default());
+    let task = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&task);
+
+    // Dropping the setter must wake the task and resolve the future as cancelled
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending, "Future is not pending");
+    drop(setter);
+    assert_eq!(waker.wakeups.load(SeqCst), 1, "Task has not been woken");
+    assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(None), "Future has not been cancelled");
+}
+
+#[test]
+fn poll_thread() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set(7);
+    });
+    assert_eq!(block_on(getter.into_async()), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn await_getter() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set(7);
+    });
+    assert_eq!(block_on(Box::pin(async move { getter.await })), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn poll_migrate() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut future = getter.into_async();
+    let (first, second) = (Arc::new(CountingWaker::default()), Arc::new(CountingWaker::default()));
+
+    // Poll from two different tasks in sequence
+    let task = Waker::from(first.clone());
+    assert_eq!(Pin::new(&mut future).poll(&mut Context::from_waker(&task)), Poll::Pending, "Future is not pending");
+    let task = Waker::from(second.clone());
+    assert_eq!(Pin::new(&mut future).poll(&mut Context::from_waker(&task)), Poll::Pending, "Future is not pending");
+
+    // Complete the future from another thread; only the most recently registered waker must be woken
+    thread::spawn(move || setter.set(7)).join().expect("Setter thread panicked");
+    assert_eq!(first.wakeups.load(SeqCst), 0, "Stale task has been woken");
+    assert_eq!(second.wakeups.load(SeqCst), 1, "Task has not been woken");
+    let result = Pin::new(&mut future).poll(&mut Context::from_waker(&task));
+    assert_eq!(result, Poll::Ready(Some(7)), "Future has invalid result");
+}
+
+#[test]
+fn poll_unordered() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut future = getter.into_async();
+    let waker = Arc::new(CountingWaker::default());
+    let task = Waker::from(waker.clone());
+
+    // Poll from a dummy task first, then move the future into a `FuturesUnordered`
+    assert_eq!(Pin::new(&mut future).poll(&mut Context::from_waker(&task)), Poll::Pending, "Future is not pending");
+    let mut futures: FuturesUnordered<_> = [future].into_iter().collect();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set(7);
+    });
+    assert_eq!(futures::executor::block_on(futures.next()), Some(Some(7)), "Future has invalid result");
+    assert_eq!(waker.wakeups.load(SeqCst), 0, "Stale task has been woken");
+}
+
+#[test]
+fn drop_pending() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut future = getter.into_async();
+    let waker = Arc::new(CountingWaker::default());
+    let task = Waker::from(waker.clone());
+
+    // Dropping the pending adapter must cancel the future like dropping the getter
+    assert_eq!(Pin::new(&mut future).poll(&mut Context::from_waker(&task)), Poll::Pending, "Future is not pending");
+    drop(future);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(waker.wakeups.load(SeqCst), 0, "Dropped task has been woken");
+}
+
+#[test]
+fn setter_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let waker = Arc::new(CountingWaker::default());
+    let task = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&task);
+
+    // The future must be pending until the getter is dropped, which must wake the task
+    let mut cancelled = setter.cancelled();
+    assert_eq!(Pin::new(&mut cancelled).poll(&mut cx), Poll::Pending, "Future is not pending");
+    drop(getter);
+    assert_eq!(waker.wakeups.load(SeqCst), 1, "Task has not been woken");
+    assert_eq!(Pin::new(&mut cancelled).poll(&mut cx), Poll::Ready(()), "Future has not been cancelled");
+}
+
+#[test]
+fn setter_cancelled_immediately() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    getter.cancel();
+    block_on(setter.cancelled());
+}
+
+#[test]
+fn setter_cancelled_race() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        getter.cancel();
+    });
+
+    // Race some work that never finishes against the cancellation
+    let work = futures::future::pending::<u8>();
+    let cancelled = futures::executor::block_on(async {
+        futures::select! {
+            _ = Box::pin(setter.cancelled()).fuse() => true,
+            _ = Box::pin(work).fuse() => false,
+        }
+    });
+    assert!(cancelled, "Future has not been cancelled");
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn setter_cancelled_on_complete() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let collected: Getter<Vec<u8>> = [getter].into_iter().collect();
+    let waker = Arc::new(CountingWaker::default());
+    let task = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&task);
+
+    // A cloned setter must be woken once the other setter completes the future consumed by the combinator
+    let clone = setter.clone();
+    let mut cancelled = clone.cancelled();
+    assert_eq!(Pin::new(&mut cancelled).poll(&mut cx), Poll::Pending, "Future is not pending");
+    setter.set(7);
+    assert_eq!(waker.wakeups.load(SeqCst), 1, "Task has not been woken");
+    assert_eq!(Pin::new(&mut cancelled).poll(&mut cx), Poll::Ready(()), "Future is still pending");
+    assert_eq!(collected.wait(), Some(vec![7]), "Future has invalid result");
+}
+
+#[test]
+fn setter_cancelled_wakers() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Dropped futures must not keep their wakers alive
+    let wakers: Vec<_> = (0..16).map(|_| Arc::new(CountingWaker::default())).collect();
+    for waker in wakers.iter() {
+        let task = Waker::from(waker.clone());
+        let mut cancelled = setter.cancelled();
+        assert_eq!(Pin::new(&mut cancelled).poll(&mut Context::from_waker(&task)), Poll::Pending, "Future is pending");
+    }
+    assert!(wakers.iter().all(|waker| Arc::strong_count(waker) == 1), "Stale waker has been retained");
+
+    // Polling from another task must replace the waker
+    let (first, second) = (Arc::new(CountingWaker::default()), Arc::new(CountingWaker::default()));
+    let mut cancelled = setter.cancelled();
+    for waker in [&first, &second] {
+        let task = Waker::from(waker.clone());
+        assert_eq!(Pin::new(&mut cancelled).poll(&mut Context::from_waker(&task)), Poll::Pending, "Future is pending");
+    }
+    assert_eq!(Arc::strong_count(&first), 1, "Stale waker has been retained");
+
+    // Only the most recently registered waker must be woken
+    drop(getter);
+    assert_eq!(first.wakeups.load(SeqCst), 0, "Stale task has been woken");
+    assert_eq!(second.wakeups.load(SeqCst), 1, "Task has not been woken");
+}
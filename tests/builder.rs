@@ -0,0 +1,43 @@
+use std::time::{Duration, Instant};
+use tiny_future::FutureBuilder;
+
+#[test]
+fn builder() {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    let builder = FutureBuilder::new().name("fetch-user").deadline(deadline);
+
+    // Stamp out several similarly configured futures
+    for value in 0..3u8 {
+        let (setter, getter) = builder.build();
+        assert_eq!(getter.label(), Some("fetch-user"), "Future has invalid label");
+        assert_eq!(getter.deadline(), Some(deadline), "Future has invalid deadline");
+        setter.set(value);
+        assert_eq!(getter.wait(), Some(value), "Future has invalid result");
+    }
+}
+
+#[test]
+fn builder_unconfigured() {
+    // An unconfigured builder must behave like `new`
+    let (setter, getter) = FutureBuilder::default().build::<u8>();
+    assert_eq!(getter.label(), None, "Future has invalid label");
+    assert_eq!(getter.deadline(), None, "Future has invalid deadline");
+    drop(setter);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn builder_with_default() {
+    let builder = FutureBuilder::new().name("fetch-user");
+
+    // Dropping the setter must complete the future with the default value
+    let (setter, getter) = builder.build_with_default::<u8>();
+    assert_eq!(getter.label(), Some("fetch-user"), "Future has invalid label");
+    drop(setter);
+    assert_eq!(getter.wait(), Some(0), "Future has invalid result");
+
+    // An explicit result must not be overridden
+    let (setter, getter) = builder.build_with_default::<u8>();
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
@@ -0,0 +1,42 @@
+use std::{iter, thread, time::Duration};
+
+#[test]
+fn chunked() {
+    let (setter, getter) = tiny_future::chunked::<usize, String>();
+
+    // Push back-to-back chunks faster than the getter consumes them
+    thread::spawn(move || {
+        for chunk in ["a", "b", "c"] {
+            setter.push(chunk.to_string()).expect("Future has been cancelled");
+        }
+        setter.set(3);
+    });
+
+    // Drain the chunks in order, then take the final value
+    thread::sleep(Duration::from_millis(50));
+    let chunks: Vec<_> = iter::from_fn(|| getter.next_chunk()).collect();
+    assert_eq!(chunks, ["a", "b", "c"], "Future has invalid chunks");
+    assert_eq!(getter.finish(), Some(3), "Future has invalid result");
+}
+
+#[test]
+fn chunked_setter_dropped() {
+    let (setter, getter) = tiny_future::chunked::<u8, u8>();
+
+    // Dropping the setter without a final value must drop the queued chunks and wake the getter
+    setter.push(1).expect("Future has been cancelled");
+    drop(setter);
+    assert_eq!(getter.next_chunk(), None, "Queued chunks have not been dropped");
+    assert_eq!(getter.finish(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn chunked_getter_dropped() {
+    let (setter, getter) = tiny_future::chunked::<u8, u8>();
+
+    // Dropping the getter must reject further chunks
+    setter.push(1).expect("Future has been cancelled");
+    drop(getter);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(setter.push(2), Err(2), "Chunk has been accepted after cancellation");
+}
@@ -0,0 +1,486 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tiny_future::{Either, JoinOutcome};
+
+/// Spawns a thread that sets the result after a short delay
+fn delayed<T>(value: T) -> tiny_future::Getter<T>
+where
+    T: Send + 'static,
+{
+    let (setter, getter) = tiny_future::new();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set(value);
+    });
+    getter
+}
+
+#[test]
+fn and_then() {
+    // Chain three stages
+    let getter = delayed(1u8).and_then(|value| delayed(value + 2)).and_then(|value| delayed(value * 3));
+    assert_eq!(getter.wait(), Some(9), "Future has invalid result");
+}
+
+#[test]
+fn and_then_middle_cancelled() {
+    // Chain three stages where the middle stage is cancelled
+    let getter = delayed(1u8)
+        .and_then(|_| {
+            let (setter, getter) = tiny_future::new::<u8>();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(50));
+                drop(setter);
+            });
+            getter
+        })
+        .and_then(|_| -> tiny_future::Getter<u8> { panic!("Final stage has been started") });
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn and_then_cancel_in_flight() {
+    let (first_setter, first_getter) = tiny_future::new::<u8>();
+    let started = Arc::new(AtomicBool::new(false));
+
+    // Cancel the chain while the first stage is in flight
+    let started_ = started.clone();
+    let getter = first_getter.and_then(move |value| {
+        started_.store(true, SeqCst);
+        delayed(value)
+    });
+    getter.cancel();
+    assert!(first_setter.is_cancelled(), "First stage has not been cancelled");
+
+    // The second stage must never be started
+    first_setter.set(7);
+    assert!(!started.load(SeqCst), "Second stage has been started");
+}
+
+#[test]
+fn and_then_cancel_second_stage() {
+    let (first_setter, first_getter) = tiny_future::new::<u8>();
+    let (second_setter, second_getter) = tiny_future::new::<u8>();
+
+    // Cancel the chain while the second stage is in flight
+    let getter = first_getter.and_then(move |_| second_getter);
+    first_setter.set(7);
+    assert!(!second_setter.is_cancelled(), "Second stage has been cancelled too early");
+    drop(getter);
+    assert!(second_setter.is_cancelled(), "Second stage has not been cancelled");
+}
+
+#[test]
+fn zip_first_completes_first() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<&str>();
+    let getter = tiny_future::join2(a_getter, b_getter);
+
+    // Complete the first input first
+    a_setter.set(7);
+    assert!(!getter.is_ready(), "Joined future is ready too early");
+    b_setter.set("seven");
+    assert_eq!(getter.wait(), Some((7, "seven")), "Future has invalid result");
+}
+
+#[test]
+fn zip_second_completes_first() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<&str>();
+    let getter = a_getter.zip(b_getter);
+
+    // Complete the second input first
+    b_setter.set("seven");
+    assert!(!getter.is_ready(), "Joined future is ready too early");
+    a_setter.set(7);
+    assert_eq!(getter.wait(), Some((7, "seven")), "Future has invalid result");
+}
+
+#[test]
+fn zip_mixed() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = a_getter.zip(b_getter);
+
+    // Complete the first input and cancel the second one
+    a_setter.set(7);
+    drop(b_setter);
+    assert_eq!(getter.wait(), None, "Joined future has not been cancelled");
+
+    // Cancelling the first input must cancel the second one
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = a_getter.zip(b_getter);
+    drop(a_setter);
+    assert!(b_setter.is_cancelled(), "Second input has not been cancelled");
+    assert_eq!(getter.wait(), None, "Joined future has not been cancelled");
+}
+
+#[test]
+fn zip_drop() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+
+    // Dropping the joined getter must cancel both inputs
+    drop(a_getter.zip(b_getter));
+    assert!(a_setter.is_cancelled(), "First input has not been cancelled");
+    assert!(b_setter.is_cancelled(), "Second input has not been cancelled");
+}
+
+#[test]
+fn race() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = tiny_future::race(a_getter, b_getter);
+
+    // The first result wins and the loser is cancelled
+    b_setter.set(4);
+    assert!(a_setter.is_cancelled(), "Loser has not been cancelled");
+    assert_eq!(getter.wait(), Some(4), "Future has invalid result");
+}
+
+#[test]
+fn race_either() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<&str>();
+    let getter = tiny_future::race_either(a_getter, b_getter);
+
+    // The first result wins and the loser is cancelled
+    a_setter.set(7);
+    assert!(b_setter.is_cancelled(), "Loser has not been cancelled");
+    assert_eq!(getter.wait(), Some(Either::Left(7)), "Future has invalid result");
+}
+
+#[test]
+fn race_cancelled() {
+    // A single cancelled input does not cancel the race
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = tiny_future::race(a_getter, b_getter);
+    drop(a_setter);
+    b_setter.set(4);
+    assert_eq!(getter.wait(), Some(4), "Future has invalid result");
+
+    // Both cancelled inputs cancel the race
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = tiny_future::race(a_getter, b_getter);
+    drop(a_setter);
+    drop(b_setter);
+    assert_eq!(getter.wait(), None, "Raced future has not been cancelled");
+}
+
+#[test]
+fn race_stress() {
+    for _ in 0..500 {
+        let (a_setter, a_getter) = tiny_future::new::<u8>();
+        let (b_setter, b_getter) = tiny_future::new::<u8>();
+        let getter = tiny_future::race(a_getter, b_getter);
+
+        // Complete both inputs at nearly the same instant
+        let a_thread = thread::spawn(move || a_setter.set(1));
+        let b_thread = thread::spawn(move || b_setter.set(2));
+        let result = getter.wait();
+        a_thread.join().expect("Setter thread panicked");
+        b_thread.join().expect("Setter thread panicked");
+
+        // Exactly one value must be delivered
+        assert!(matches!(result, Some(1) | Some(2)), "Future has invalid result: {result:?}");
+    }
+}
+
+#[test]
+fn or_first() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = a_getter.or(b_getter);
+
+    // The first result wins and the fallback is cancelled
+    a_setter.set(7);
+    assert!(b_setter.is_cancelled(), "Fallback has not been cancelled");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn or_fallback() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = a_getter.or(b_getter);
+
+    // The fallback is consulted if the first future is cancelled
+    drop(a_setter);
+    assert!(!b_setter.is_cancelled(), "Fallback has been cancelled");
+    b_setter.set(4);
+    assert_eq!(getter.wait(), Some(4), "Future has invalid result");
+
+    // Both futures are cancelled
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+    let getter = a_getter.or(b_getter);
+    drop(a_setter);
+    drop(b_setter);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn or_drop() {
+    let (a_setter, a_getter) = tiny_future::new::<u8>();
+    let (b_setter, b_getter) = tiny_future::new::<u8>();
+
+    // Dropping the combined getter must cancel both sources
+    drop(a_getter.or(b_getter));
+    assert!(a_setter.is_cancelled(), "First source has not been cancelled");
+    assert!(b_setter.is_cancelled(), "Fallback has not been cancelled");
+}
+
+#[test]
+fn flatten() {
+    let (outer_setter, outer_getter) = tiny_future::new::<tiny_future::Getter<u8>>();
+    let getter = outer_getter.flatten();
+
+    // Complete both layers
+    outer_setter.set(delayed(7));
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn flatten_inner_dropped() {
+    let (outer_setter, outer_getter) = tiny_future::new::<tiny_future::Getter<u8>>();
+    let (inner_setter, inner_getter) = tiny_future::new::<u8>();
+    let getter = outer_getter.flatten();
+
+    // Complete the outer layer and drop the inner setter afterwards
+    outer_setter.set(inner_getter);
+    assert!(!getter.is_ready(), "Future is ready too early");
+    drop(inner_setter);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn flatten_cancel() {
+    // Cancel while the outer layer is pending
+    let (outer_setter, outer_getter) = tiny_future::new::<tiny_future::Getter<u8>>();
+    drop(outer_getter.flatten());
+    assert!(outer_setter.is_cancelled(), "Outer layer has not been cancelled");
+
+    // Cancel while the inner layer is pending
+    let (outer_setter, outer_getter) = tiny_future::new::<tiny_future::Getter<u8>>();
+    let (inner_setter, inner_getter) = tiny_future::new::<u8>();
+    let getter = outer_getter.flatten();
+    outer_setter.set(inner_getter);
+    getter.cancel();
+    assert!(inner_setter.is_cancelled(), "Inner layer has not been cancelled");
+}
+
+#[test]
+fn with_timeout_expired() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let getter = getter.with_timeout(Duration::from_millis(100));
+
+    // The wrapped getter must be cancelled on expiry, and so must be the source
+    assert_eq!(getter.wait(), None, "Future has not been cancelled on expiry");
+    assert!(setter.is_cancelled(), "Source has not been cancelled on expiry");
+}
+
+#[test]
+fn with_timeout_in_time() {
+    let getter = delayed(7u8).with_timeout(Duration::from_millis(500));
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn with_timeout_cancel() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let getter = getter.with_timeout(Duration::from_secs(3600));
+
+    // Dropping the wrapped getter must cancel the source
+    drop(getter);
+    assert!(setter.is_cancelled(), "Source has not been cancelled");
+}
+
+#[test]
+fn fold() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4).map(|_| tiny_future::new::<u8>()).unzip();
+
+    // Resolve the inputs in reverse order and cancel one of them
+    thread::spawn(move || {
+        for (value, setter) in setters.into_iter().enumerate().rev() {
+            thread::sleep(Duration::from_millis(20));
+            match value {
+                1 => drop(setter),
+                value => setter.set(value as u8),
+            }
+        }
+    });
+
+    // The results must be folded in completion order, skipping the cancelled input
+    let fold = tiny_future::fold(getters, Vec::new(), |mut acc, value| {
+        acc.push(value);
+        acc
+    });
+    assert_eq!(fold.start().wait(), Some(vec![3, 2, 0]), "Future has invalid result");
+}
+
+#[test]
+fn fold_concurrent() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..16).map(|_| tiny_future::new::<u8>()).unzip();
+    let fold = tiny_future::fold(getters, 0u32, |acc, value| {
+        // Fold slowly so that concurrent results are queued
+        thread::sleep(Duration::from_millis(5));
+        acc + u32::from(value)
+    });
+    let getter = fold.start();
+
+    // Resolve all inputs concurrently
+    let threads: Vec<_> = setters.into_iter().map(|setter| thread::spawn(move || setter.set(1))).collect();
+    for thread in threads {
+        thread.join().expect("Setter thread panicked");
+    }
+    assert_eq!(getter.wait(), Some(16), "Future has invalid result");
+}
+
+#[test]
+fn fold_panic() {
+    let (mut setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let fold = tiny_future::fold(getters, 0, |acc, value| match value {
+        13 => panic!("Unlucky value"),
+        value => acc + value,
+    });
+    let getter = fold.start();
+
+    // A panicking fold must fail the fold without unwinding the producer or poisoning the remaining inputs
+    let unlucky = setters.remove(0);
+    let result = thread::spawn(move || unlucky.set(13)).join();
+    assert!(result.is_ok(), "Panic has been propagated to the producer");
+    for setter in setters {
+        setter.set(7);
+    }
+
+    // The panic must be delivered to the folded future
+    let JoinOutcome::Panicked(payload) = getter.wait_outcome() else {
+        panic!("Panic has not been delivered");
+    };
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"Unlucky value"), "Panic has invalid payload");
+}
+
+#[test]
+fn fold_empty() {
+    let getter = tiny_future::fold(Vec::<tiny_future::Getter<u8>>::new(), 7, |acc, value| acc + value).start();
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn fold_strict() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let getter = tiny_future::fold(getters, 0, |acc, value| acc + value).strict().start();
+
+    // A cancelled input must fail the whole fold and cancel the remaining inputs
+    let mut setters = setters.into_iter();
+    setters.next().expect("Setter is missing").set(1);
+    drop(setters.next());
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert!(setters.all(|setter| setter.is_cancelled()), "Remaining inputs have not been cancelled");
+}
+
+#[test]
+fn fold_cancel() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let getter = tiny_future::fold(getters, 0, |acc, value| acc + value).start();
+
+    // Dropping the folded getter must cancel all inputs
+    drop(getter);
+    assert!(setters.iter().all(|setter| setter.is_cancelled()), "Inputs have not been cancelled");
+}
+
+#[test]
+fn quorum() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let getter = tiny_future::quorum(getters, 2);
+
+    // The quorum must complete in completion order and cancel the stragglers
+    let [first, second, third]: [_; 3] = setters.try_into().expect("Setters are missing");
+    second.set(2);
+    first.set(1);
+    assert_eq!(getter.wait(), Some(vec![2, 1]), "Future has invalid result");
+    assert!(third.is_cancelled(), "Straggler has not been cancelled");
+}
+
+#[test]
+fn quorum_impossible() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let getter = tiny_future::quorum(getters, 2);
+
+    // Cancelling two inputs must cancel the quorum without waiting for the last input
+    let mut setters = setters.into_iter();
+    drop(setters.next());
+    drop(setters.next());
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert!(setters.all(|setter| setter.is_cancelled()), "Remaining inputs have not been cancelled");
+}
+
+#[test]
+fn quorum_trivial() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert_eq!(tiny_future::quorum(vec![getter], 0).wait(), Some(Vec::new()), "Future has invalid result");
+    assert!(setter.is_cancelled(), "Input has not been cancelled");
+
+    // A quorum that exceeds the amount of inputs must be cancelled immediately
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert_eq!(tiny_future::quorum(vec![getter], 2).wait(), None, "Future has not been cancelled");
+    assert!(setter.is_cancelled(), "Input has not been cancelled");
+}
+
+#[test]
+fn collect() {
+    let getters = vec![delayed(1u8), tiny_future::ready(2), delayed(3)];
+    let getter: tiny_future::Getter<Vec<u8>> = getters.into_iter().collect();
+    assert_eq!(getter.wait(), Some(vec![1, 2, 3]), "Future has invalid result");
+
+    // Collecting nothing must complete immediately
+    let getter: tiny_future::Getter<Vec<u8>> = std::iter::empty().collect();
+    assert_eq!(getter.wait(), Some(Vec::new()), "Future has invalid result");
+}
+
+#[test]
+fn collect_cancelled() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let getter: tiny_future::Getter<Vec<u8>> = getters.into_iter().collect();
+
+    // Cancelling one input must cancel the join and the remaining inputs
+    let mut setters = setters.into_iter();
+    drop(setters.next());
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert!(setters.all(|setter| setter.is_cancelled()), "Remaining inputs have not been cancelled");
+}
+
+#[test]
+fn collect_result() {
+    let getters = vec![delayed(Ok::<u8, &str>(1)), tiny_future::ready(Ok(2))];
+    let getter: tiny_future::Getter<Result<Vec<u8>, &str>> = getters.into_iter().collect();
+    assert_eq!(getter.wait(), Some(Ok(vec![1, 2])), "Future has invalid result");
+
+    // The first error must complete the join and cancel the remaining inputs
+    let (setter, pending) = tiny_future::new::<Result<u8, &str>>();
+    let getters = vec![pending, tiny_future::ready(Err("failed"))];
+    let getter: tiny_future::Getter<Result<Vec<u8>, &str>> = getters.into_iter().collect();
+    assert_eq!(getter.wait(), Some(Err("failed")), "Future has invalid result");
+    assert!(setter.is_cancelled(), "Remaining input has not been cancelled");
+}
+
+#[test]
+fn lazy_combinators() {
+    let getter = tiny_future::lazy(|| 4u8).and_then(|value| tiny_future::lazy(move || value + 3));
+    assert_eq!(getter.wait_timeout(Duration::from_secs(5)).ok(), Some(Some(7)), "Future has invalid result");
+
+    // Lazy futures must be evaluated when collected
+    let getter: tiny_future::Getter<Vec<u8>> = (0..3).map(|value| tiny_future::lazy(move || value)).collect();
+    assert_eq!(getter.wait_timeout(Duration::from_secs(5)).ok(), Some(Some(vec![0, 1, 2])), "Future has invalid result");
+    let getter = tiny_future::join2(tiny_future::lazy(|| 4u8), tiny_future::lazy(|| 7u8));
+    assert_eq!(getter.wait_timeout(Duration::from_secs(5)).ok(), Some(Some((4, 7))), "Future has invalid result");
+}
@@ -0,0 +1,70 @@
+use std::{sync::Arc, thread, time::Duration};
+use tiny_future::CompletionSet;
+
+#[test]
+fn completion_order() {
+    let set = CompletionSet::new();
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4).map(|_| tiny_future::new::<u8>()).unzip();
+    let keys: Vec<_> = getters.into_iter().map(|getter| set.insert(getter)).collect();
+    assert_eq!(set.len(), 4, "Completion set has invalid length");
+
+    // Resolve the futures in reverse order
+    thread::spawn(move || {
+        for (value, setter) in setters.into_iter().enumerate().rev() {
+            thread::sleep(Duration::from_millis(50));
+            match value {
+                1 => drop(setter),
+                value => setter.set(value as u8),
+            }
+        }
+    });
+
+    // The results must be yielded in completion order
+    let results: Vec<_> = std::iter::from_fn(|| set.next()).collect();
+    let expected = vec![(keys[3], Some(3)), (keys[2], Some(2)), (keys[1], None), (keys[0], Some(0))];
+    assert_eq!(results, expected, "Completion set has yielded invalid results");
+    assert!(set.is_empty(), "Completion set is not empty");
+    assert_eq!(set.next(), None, "Empty completion set has yielded a result");
+}
+
+#[test]
+fn insert_while_blocked() {
+    let set = Arc::new(CompletionSet::new());
+    let (pending_setter, pending_getter) = tiny_future::new::<u8>();
+    set.insert(pending_getter);
+
+    // Block in `next` on another thread
+    let waiter = {
+        let set = set.clone();
+        thread::spawn(move || set.next())
+    };
+
+    // Insert an already resolved getter while the waiter is blocked
+    thread::sleep(Duration::from_millis(100));
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+    let key = set.insert(getter);
+    assert_eq!(waiter.join().expect("Waiter thread panicked"), Some((key, Some(7))), "Completion set has invalid result");
+
+    // The pending getter must still be a member
+    assert_eq!(set.len(), 1, "Completion set has invalid length");
+    assert!(!pending_setter.is_cancelled(), "Pending member has been cancelled");
+}
+
+#[test]
+fn drop_cancels_members() {
+    let set = CompletionSet::new();
+    let (setter, getter) = tiny_future::new::<u8>();
+    set.insert(getter);
+
+    // Dropping the set must cancel its members
+    drop(set);
+    assert!(setter.is_cancelled(), "Member has not been cancelled");
+}
+
+#[test]
+fn completion_lazy() {
+    let set = CompletionSet::new();
+    let key = set.insert(tiny_future::lazy(|| 7u8));
+    assert_eq!(set.next(), Some((key, Some(7))), "Completion set has invalid result");
+}
@@ -0,0 +1,68 @@
+use std::sync::Mutex;
+use tiny_future::{FutureBuilder, FutureId};
+
+/// The identities of the reported getters
+static REPORTED: Mutex<Vec<FutureId>> = Mutex::new(Vec::new());
+
+/// Installs a hook that records the reported getters
+fn install() {
+    tiny_future::set_unconsumed_hook(|id, _| REPORTED.lock().expect("Reports are poisoned").push(id));
+}
+/// Whether the getter with the given identity has been reported or not
+fn is_reported(id: FutureId) -> bool {
+    REPORTED.lock().expect("Reports are poisoned").contains(&id)
+}
+
+#[test]
+fn must_consume() {
+    install();
+
+    // Dropping an unconsumed getter must be reported
+    let (_setter, getter) = FutureBuilder::new().name("fetch-user").must_consume().build::<u8>();
+    let id = getter.id();
+    drop(getter);
+    assert!(is_reported(id), "Unconsumed getter has not been reported");
+}
+
+#[test]
+fn must_consume_consumed() {
+    install();
+    let builder = FutureBuilder::new().must_consume();
+
+    // Waiting, polling, and detaching must count as consumed
+    let (setter, getter) = builder.build::<u8>();
+    let waited = getter.id();
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+
+    let (_setter, getter) = builder.build::<u8>();
+    let polled = getter.id();
+    let getter = getter.try_get().expect_err("Future is not pending");
+    drop(getter);
+
+    let (_setter, getter) = builder.build::<u8>();
+    let detached = getter.id();
+    getter.detach();
+
+    // Getters without the option must never be reported
+    let (_setter, getter) = tiny_future::new::<u8>();
+    let unchecked = getter.id();
+    drop(getter);
+
+    let reported = [waited, polled, detached, unchecked].into_iter().any(is_reported);
+    assert!(!reported, "Consumed getter has been reported");
+}
+
+#[test]
+fn must_consume_reentrant() {
+    // A hook that replaces itself must not deadlock
+    tiny_future::set_unconsumed_hook(|id, _| {
+        install();
+        REPORTED.lock().expect("Reports are poisoned").push(id);
+    });
+
+    let (_setter, getter) = FutureBuilder::new().must_consume().build::<u8>();
+    let id = getter.id();
+    drop(getter);
+    assert!(is_reported(id), "Unconsumed getter has not been reported");
+}
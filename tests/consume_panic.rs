@@ -0,0 +1,11 @@
+#![cfg(debug_assertions)]
+
+use tiny_future::FutureBuilder;
+
+#[test]
+#[should_panic(expected = "has been dropped without being consumed?!")]
+fn must_consume_panic() {
+    // Without a hook, dropping an unconsumed getter must panic in debug builds
+    let (_setter, getter) = FutureBuilder::new().must_consume().build::<u8>();
+    drop(getter);
+}
@@ -0,0 +1,59 @@
+use std::{
+    sync::{mpsc, Arc},
+    thread,
+    time::Duration,
+};
+use tiny_future::Event;
+
+#[test]
+fn event() {
+    let event = Arc::new(Event::new());
+    assert!(!event.is_set(), "Event has been set too early");
+
+    // Spawn many waiters that must all be released by a single set
+    let waiters: Vec<_> = (0..16)
+        .map(|_| {
+            let event = event.clone();
+            thread::spawn(move || event.wait_timeout(Duration::from_secs(2)))
+        })
+        .collect();
+    thread::sleep(Duration::from_millis(50));
+    event.set();
+    for waiter in waiters {
+        assert!(waiter.join().expect("Waiter thread panicked"), "Waiter has not been released");
+    }
+
+    // The event must stay set for future waiters
+    assert!(event.is_set(), "Event is not set");
+    event.wait();
+}
+
+#[test]
+fn event_reset() {
+    let event = Event::new();
+    event.set();
+    event.reset();
+    assert!(!event.is_set(), "Event has not been reset");
+    assert!(!event.wait_timeout(Duration::from_millis(10)), "Event has been set after reset");
+}
+
+#[test]
+fn event_reset_race() {
+    let event = Arc::new(Event::new());
+    let (started, ready) = mpsc::channel();
+
+    // A blocked waiter must be released even if the event is reset before the waiter wakes up
+    let waiter = {
+        let event = event.clone();
+        thread::spawn(move || {
+            started.send(()).expect("Failed to signal start");
+            event.wait_timeout(Duration::from_secs(2))
+        })
+    };
+    ready.recv().expect("Waiter has not started");
+    thread::sleep(Duration::from_millis(50));
+    event.set();
+    event.reset();
+    assert!(waiter.join().expect("Waiter thread panicked"), "Waiter has not been released");
+    assert!(!event.is_set(), "Event has not been reset");
+}
@@ -0,0 +1,45 @@
+use std::{thread, time::Duration};
+
+#[test]
+fn exchange() {
+    let (left, right) = tiny_future::exchange::<u8, String>();
+
+    // Both sides must receive the value of the other side
+    let peer = thread::spawn(move || right.exchange("seven".to_string()));
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(left.exchange(7).as_deref(), Some("seven"), "Exchange has invalid result");
+    assert_eq!(peer.join().expect("Peer thread panicked"), Some(7), "Exchange has invalid result");
+}
+
+#[test]
+fn exchange_dropped() {
+    let (left, right) = tiny_future::exchange::<u8, u8>();
+
+    // Dropping one side must unblock the other side
+    let peer = thread::spawn(move || left.exchange(7));
+    thread::sleep(Duration::from_millis(50));
+    drop(right);
+    assert_eq!(peer.join().expect("Peer thread panicked"), None, "Exchange has not failed");
+}
+
+#[test]
+fn exchange_timeout() {
+    let (left, right) = tiny_future::exchange::<u8, u8>();
+
+    // A side that times out must withdraw so that the other side fails too
+    assert_eq!(left.exchange_timeout(7, Duration::from_millis(10)), None, "Exchange has not timed out");
+    assert_eq!(right.exchange(8), None, "Exchange has not failed");
+}
+
+#[test]
+fn exchange_timeout_race() {
+    for _ in 0..64 {
+        let (left, right) = tiny_future::exchange::<u8, u8>();
+
+        // Race a timed exchange against the other side; both sides must agree on the outcome
+        let peer = thread::spawn(move || right.exchange_timeout(8, Duration::from_millis(1)));
+        let result = left.exchange_timeout(7, Duration::from_millis(1));
+        let peer = peer.join().expect("Peer thread panicked");
+        assert_eq!(result.is_some(), peer.is_some(), "Exchange has inconsistent outcome");
+    }
+}
@@ -0,0 +1,74 @@
+use std::{
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tiny_future::Executor;
+
+#[test]
+fn executor() {
+    let executor = Executor::new(4);
+    let getters: Vec<_> = (0..64u32).map(|value| executor.submit(move || value * 2)).collect();
+    for (value, getter) in (0..64u32).zip(getters) {
+        assert_eq!(getter.wait(), Some(value * 2), "Future has invalid result");
+    }
+}
+
+#[test]
+fn executor_panic() {
+    let executor = Executor::new(1);
+
+    // A panicking job must cancel its getter without killing the worker
+    let getter = executor.submit(|| -> u8 { panic::panic_any("Test panic") });
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert_eq!(executor.submit(|| 7).wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn executor_skip_cancelled() {
+    let executor = Executor::new(1);
+
+    // Block the worker and queue a job behind it
+    let (gate, blocker) = tiny_future::new::<()>();
+    let running = executor.submit(move || blocker.wait());
+    let started = Arc::new(AtomicBool::default());
+    let queued = {
+        let started = started.clone();
+        executor.submit(move || started.store(true, SeqCst))
+    };
+
+    // Cancel the queued job before it has been started
+    drop(queued);
+    gate.set(());
+    assert_eq!(running.wait(), Some(Some(())), "Future has invalid result");
+    assert_eq!(executor.submit(|| 7).wait(), Some(7), "Future has invalid result");
+    assert!(!started.load(SeqCst), "Cancelled job has been started");
+}
+
+#[test]
+fn executor_shutdown() {
+    let executor = Executor::new(1);
+
+    // Block the worker and queue a job behind it
+    let (gate, blocker) = tiny_future::new::<()>();
+    let running = executor.submit(move || blocker.wait().map(|_| 7));
+    thread::sleep(Duration::from_millis(50));
+    let queued = executor.submit(|| 4);
+    assert_eq!(executor.queued(), 1, "Job has not been queued");
+
+    // Release the running job while shutting down
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        gate.set(());
+    });
+    executor.shutdown();
+
+    // The running job must finish while the queued job is cancelled
+    assert_eq!(running.wait(), Some(Some(7)), "Future has invalid result");
+    assert_eq!(queued.wait(), None, "Future has not been cancelled");
+    assert_eq!(executor.submit(|| 1).wait(), None, "Future has not been cancelled");
+}
@@ -0,0 +1,49 @@
+#![cfg(all(unix, feature = "event-fd"))]
+
+use std::{
+    os::fd::{AsRawFd, BorrowedFd},
+    thread,
+    time::Duration,
+};
+
+/// Polls `fd` for readability with the given timeout in milliseconds
+fn is_readable(fd: BorrowedFd, timeout: i32) -> bool {
+    let mut pollfd = libc::pollfd { fd: fd.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+    let result = unsafe { libc::poll(&mut pollfd, 1, timeout) };
+    assert!(result >= 0, "Failed to poll event fd");
+    result == 1 && pollfd.revents & libc::POLLIN != 0
+}
+
+#[test]
+fn event_fd_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert!(!is_readable(getter.as_event_fd(), 0), "Event fd is readable too early");
+
+    // Set the result from another thread and wait for the descriptor
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+    assert!(is_readable(getter.as_event_fd(), 5000), "Event fd has not been signalled");
+    assert_eq!(getter.try_get().ok(), Some(Some(7)), "Future has invalid result");
+}
+
+#[test]
+fn event_fd_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let fd = getter.as_event_fd();
+    assert!(!is_readable(fd, 0), "Event fd is readable too early");
+
+    // Dropping the setter must signal the descriptor
+    drop(setter);
+    assert!(is_readable(fd, 0), "Event fd has not been signalled");
+}
+
+#[test]
+fn event_fd_lazy() {
+    let getter = tiny_future::ready(7u8);
+
+    // A descriptor that is created after the future has been resolved must be readable immediately
+    assert!(is_readable(getter.as_event_fd(), 0), "Event fd has not been signalled");
+    assert_eq!(getter.as_event_fd().as_raw_fd(), getter.as_event_fd().as_raw_fd(), "Event fd has been recreated");
+}
@@ -1,4 +1,17 @@
-use std::{thread, time::Duration};
+use std::{
+    collections::HashMap,
+    ops::ControlFlow,
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        mpsc, Arc, Barrier,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+use tiny_future::{
+    CancelOutcome, Cancelled, DeadlineOutcome, FutureError, FutureState, Getter, InterruptOutcome, JoinOutcome,
+    SetError, WaitOutcome,
+};
 
 #[test]
 fn success() {
@@ -44,6 +57,104 @@ fn timeout() {
     assert!(result.is_err(), "Future has been set too early");
 }
 
+#[test]
+fn success_deadline() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after one second
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        setter.set(7);
+    });
+
+    // Await the result
+    let deadline = Instant::now() + Duration::from_secs(2);
+    let result = getter.wait_deadline(deadline).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn success_deadline_far_future() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after one second
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        setter.set(7);
+    });
+
+    // Await the result with a deadline roughly a century from now
+    let deadline = Instant::now() + Duration::from_secs(100 * 365 * 24 * 60 * 60);
+    let result = getter.wait_deadline(deadline).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn deadline() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after _two_ seconds
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(2));
+        setter.set(7);
+    });
+
+    // Await the result for _one_ second
+    let deadline = Instant::now() + Duration::from_secs(1);
+    let result = getter.wait_deadline(deadline);
+    assert!(result.is_err(), "Future has been set too early");
+}
+
+#[test]
+fn deadline_past() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let deadline = Instant::now();
+    thread::sleep(Duration::from_millis(10));
+
+    // A past deadline must not block if the future is pending
+    let getter = getter.wait_deadline(deadline).expect_err("Future has been set too early");
+
+    // A past deadline must still yield an available result
+    setter.set(7);
+    let result = getter.wait_deadline(deadline).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn announced_deadline() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert_eq!(getter.deadline(), None, "Future has an unexpected deadline");
+
+    // Announce a deadline and make sure the getter can see it
+    let deadline = Instant::now() + Duration::from_millis(50);
+    setter.set_deadline(deadline);
+    assert_eq!(getter.deadline(), Some(deadline), "Future has invalid deadline");
+
+    // Waiting for the announced deadline must hand the getter back
+    let getter = match getter.wait_until_deadline() {
+        DeadlineOutcome::DeadlineExceeded(getter) => getter,
+        outcome => panic!("Future has invalid outcome: {outcome:?}"),
+    };
+    assert!(Instant::now() >= deadline, "Deadline has not been reached");
+
+    // The result must still be claimable after the deadline
+    setter.set(7);
+    assert!(matches!(getter.wait_until_deadline(), DeadlineOutcome::Ready(7)), "Future has invalid result");
+}
+
+#[test]
+fn announced_deadline_met() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set_deadline(Instant::now() + Duration::from_secs(2));
+
+    // Set the result well before the announced deadline
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set(7);
+    });
+    assert!(matches!(getter.wait_until_deadline(), DeadlineOutcome::Ready(7)), "Future has invalid result");
+}
+
 #[test]
 fn cancellation_setter() {
     let (setter, getter) = tiny_future::new::<u8>();
@@ -88,3 +199,1398 @@ fn cancellation_getter() {
     thread::sleep(Duration::from_secs(2));
     assert!(setter.is_cancelled(), "Future has not been cancelled on drop");
 }
+
+#[test]
+fn try_get_ready() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+
+    // The result is already available
+    let result = getter.try_get().expect("Future is still pending");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn try_get_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    drop(setter);
+
+    // The future has been cancelled
+    let result = getter.try_get().expect("Future is still pending");
+    assert_eq!(result, None, "Future has not been marked as cancelled on drop");
+}
+
+#[test]
+fn try_get_pending() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // The future is still pending and the getter must be handed back
+    let getter = getter.try_get().expect_err("Future has been resolved too early");
+    assert!(!setter.is_cancelled(), "Future has been cancelled by a pending poll");
+
+    // The returned getter must still be usable
+    setter.set(7);
+    assert_eq!(getter.try_get().expect("Future is still pending"), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn try_get_race() {
+    for _ in 0..100 {
+        let (setter, getter) = tiny_future::new::<u8>();
+
+        // Set the result concurrently
+        let thread = thread::spawn(move || setter.set(7));
+
+        // Poll until the result is available
+        let mut getter = getter;
+        let result = loop {
+            match getter.try_get() {
+                Ok(result) => break result,
+                Err(pending) => getter = pending,
+            }
+        };
+        assert_eq!(result, Some(7), "Future has invalid result");
+        thread.join().expect("Setter thread panicked");
+    }
+}
+
+#[test]
+fn outcome_ready() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after one second
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        setter.set(7);
+    });
+
+    // Await the result
+    match getter.wait_timeout_outcome(Duration::from_secs(2)) {
+        WaitOutcome::Ready(result) => assert_eq!(result, 7, "Future has invalid result"),
+        outcome => panic!("Future has invalid outcome: {outcome:?}"),
+    }
+}
+
+#[test]
+fn outcome_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Drop the setter after one second
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        drop(setter);
+    });
+
+    // Await the cancellation
+    let outcome = getter.wait_timeout_outcome(Duration::from_secs(2));
+    assert!(matches!(outcome, WaitOutcome::Cancelled), "Future has invalid outcome: {outcome:?}");
+}
+
+#[test]
+fn outcome_timed_out() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Await the result for _one_ second
+    let WaitOutcome::TimedOut(getter) = getter.wait_timeout_outcome(Duration::from_secs(1)) else {
+        panic!("Future has been resolved too early");
+    };
+
+    // Retry after the setter fired
+    setter.set(7);
+    match getter.wait_timeout_outcome(Duration::from_secs(1)) {
+        WaitOutcome::Ready(result) => assert_eq!(result, 7, "Future has invalid result"),
+        outcome => panic!("Future has invalid outcome: {outcome:?}"),
+    }
+}
+
+#[test]
+fn outcome_cancelled_at_boundary() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Drop the setter right at the timeout boundary
+    thread::spawn(move || {
+        thread::sleep(Duration::from_secs(1));
+        drop(setter);
+    });
+
+    // Either outcome is valid, but a timed out getter must observe the cancellation afterwards
+    match getter.wait_timeout_outcome(Duration::from_secs(1)) {
+        WaitOutcome::Cancelled => (),
+        WaitOutcome::TimedOut(getter) => assert_eq!(getter.wait(), None, "Future has not been cancelled"),
+        outcome => panic!("Future has invalid outcome: {outcome:?}"),
+    }
+}
+
+#[test]
+fn result_success() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+
+    // Wait until the future is set
+    assert_eq!(getter.wait_result(), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn result_cancelled_explicit() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Cancel the future explicitly and drop the setter afterwards
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.cancel();
+        drop(setter);
+    });
+
+    // Wait until the future is cancelled
+    let error = getter.wait_result().expect_err("Future has not been cancelled");
+    assert_eq!(error, Cancelled::Explicit, "Future has invalid cancellation reason");
+}
+
+#[test]
+fn result_cancelled_dropped() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Drop the setter without setting a result
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(setter);
+    });
+
+    // Wait until the future is cancelled
+    let error = getter.wait_result().expect_err("Future has not been cancelled");
+    assert_eq!(error, Cancelled::SetterDropped, "Future has invalid cancellation reason");
+
+    // The error must be usable as a boxed standard error
+    let error: Box<dyn std::error::Error> = error.into();
+    assert!(error.to_string().contains("dropped"), "Error has invalid description");
+}
+
+#[test]
+fn fallback_success() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+    assert_eq!(getter.wait_or(4), 7, "Future has invalid result");
+
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+    assert_eq!(getter.wait_or_else(|| panic!("Fallback has been evaluated")), 7, "Future has invalid result");
+
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+    assert_eq!(getter.wait_or_default(), 7, "Future has invalid result");
+}
+
+#[test]
+fn fallback_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.cancel();
+    assert_eq!(getter.wait_or(4), 4, "Future has invalid fallback");
+
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.cancel();
+    assert_eq!(getter.wait_or_else(|| 4), 4, "Future has invalid fallback");
+
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.cancel();
+    assert_eq!(getter.wait_or_default(), 0, "Future has invalid fallback");
+}
+
+#[test]
+fn fallback_setter_dropped() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Drop the setter after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(setter);
+    });
+
+    // Wait until the future is cancelled
+    assert_eq!(getter.wait_or_else(|| 4), 4, "Future has invalid fallback");
+}
+
+#[test]
+fn state() {
+    // Pending and ready
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert_eq!(getter.state(), FutureState::Pending, "Future has invalid state");
+    assert!(!getter.is_ready(), "Future is ready too early");
+    setter.set(7);
+    assert_eq!(getter.state(), FutureState::Ready, "Future has invalid state");
+    assert!(getter.is_ready(), "Future is not ready");
+
+    // Cancelled
+    let (setter, getter) = tiny_future::new::<u8>();
+    drop(setter);
+    assert_eq!(getter.state(), FutureState::Cancelled, "Future has invalid state");
+    assert!(!getter.is_ready(), "Cancelled future is ready");
+}
+
+#[test]
+fn state_ready_wins_over_cancellation() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+
+    // Cancelling the future after the result has been set must not have any effect
+    getter.cancel();
+    assert!(!getter.is_cancelled(), "Resolved future has been cancelled");
+    assert_eq!(getter.state(), FutureState::Ready, "Future has invalid state");
+
+    // Inspecting the state must not consume the result
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn interruptible_success() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let stop = AtomicBool::new(false);
+
+    // Set the result after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+
+    // Wait until the future is set
+    match getter.wait_interruptible(&stop) {
+        InterruptOutcome::Ready(result) => assert_eq!(result, 7, "Future has invalid result"),
+        outcome => panic!("Future has invalid outcome: {outcome:?}"),
+    }
+}
+
+#[test]
+fn interruptible_interrupted() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let stop = Arc::new(AtomicBool::new(false));
+    let interrupter = getter.interrupter();
+
+    // Raise the stop flag and interrupt the waiting thread after a short delay
+    let stop_ = stop.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        stop_.store(true, SeqCst);
+        interrupter.interrupt();
+    });
+
+    // Wait until the wait is interrupted
+    let InterruptOutcome::Interrupted(getter) = getter.wait_interruptible(&stop) else {
+        panic!("Wait has not been interrupted");
+    };
+    assert!(!setter.is_cancelled(), "Future has been cancelled by the interruption");
+
+    // The future must still be completable
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn interruptible_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let stop = AtomicBool::new(true);
+    drop(setter);
+
+    // Cancellation takes precedence over the stop flag
+    let outcome = getter.wait_interruptible(&stop);
+    assert!(matches!(outcome, InterruptOutcome::Cancelled), "Future has invalid outcome: {outcome:?}");
+}
+
+#[test]
+fn spin_success() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result quickly
+    thread::spawn(move || setter.set(7));
+    assert_eq!(getter.wait_spin(Duration::from_millis(10)), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn spin_fallback() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after the spin period is over
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+    assert_eq!(getter.wait_spin(Duration::from_millis(1)), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn spin_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    drop(setter);
+
+    // Cancellation must be observed during the spin phase
+    let start = Instant::now();
+    assert_eq!(getter.wait_spin(Duration::from_secs(10)), None, "Future has not been cancelled");
+    assert!(start.elapsed() < Duration::from_secs(1), "Cancellation has not been observed while spinning");
+}
+
+#[test]
+fn detach() {
+    let (setter, getter) = tiny_future::new::<Arc<u8>>();
+    getter.detach();
+
+    // The setter must not observe a cancellation
+    thread::sleep(Duration::from_millis(100));
+    assert!(!setter.is_cancelled(), "Future has been cancelled by detaching the getter");
+
+    // Setting the result must succeed and the value must be dropped immediately
+    let value = Arc::new(7);
+    let weak = Arc::downgrade(&value);
+    setter.set(value);
+    assert!(weak.upgrade().is_none(), "Value of a detached future has not been dropped");
+}
+
+#[test]
+fn detach_after_set() {
+    let (setter, getter) = tiny_future::new::<Arc<u8>>();
+    let interrupter = getter.interrupter();
+
+    // Set the result before detaching the getter
+    let value = Arc::new(7);
+    let weak = Arc::downgrade(&value);
+    setter.set(value);
+
+    // The value must be dropped although the interrupter keeps the future alive
+    getter.detach();
+    assert!(weak.upgrade().is_none(), "Value of a detached future has not been dropped");
+    drop(interrupter);
+}
+
+#[test]
+fn on_complete_before_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+
+    // Register the callback before the result is set
+    getter.on_complete(move |result| sender.send(result).expect("Receiver is dead?!"));
+    assert!(!setter.is_cancelled(), "Future has been cancelled by registering a callback");
+    setter.set(7);
+
+    // The callback must be invoked exactly once
+    assert_eq!(receiver.recv(), Ok(Some(7)), "Callback has invalid result");
+    assert!(receiver.recv().is_err(), "Callback has been invoked more than once");
+}
+
+#[test]
+fn on_complete_after_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+
+    // Register the callback after the result is set
+    setter.set(7);
+    getter.on_complete(move |result| sender.send(result).expect("Receiver is dead?!"));
+
+    // The callback must be invoked exactly once
+    assert_eq!(receiver.recv(), Ok(Some(7)), "Callback has invalid result");
+    assert!(receiver.recv().is_err(), "Callback has been invoked more than once");
+}
+
+#[test]
+fn on_complete_cancelled() {
+    // Cancellation after registration
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+    getter.on_complete(move |result| sender.send(result).expect("Receiver is dead?!"));
+    drop(setter);
+    assert_eq!(receiver.recv(), Ok(None), "Callback has not observed the cancellation");
+    assert!(receiver.recv().is_err(), "Callback has been invoked more than once");
+
+    // Cancellation before registration
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+    drop(setter);
+    getter.on_complete(move |result| sender.send(result).expect("Receiver is dead?!"));
+    assert_eq!(receiver.recv(), Ok(None), "Callback has not observed the cancellation");
+    assert!(receiver.recv().is_err(), "Callback has been invoked more than once");
+}
+
+#[test]
+fn on_complete_race() {
+    for _ in 0..100 {
+        let (setter, getter) = tiny_future::new::<u8>();
+        let (sender, receiver) = mpsc::channel();
+
+        // Set the result concurrently to the registration
+        let thread = thread::spawn(move || setter.set(7));
+        getter.on_complete(move |result| sender.send(result).expect("Receiver is dead?!"));
+        thread.join().expect("Setter thread panicked");
+
+        // The callback must be invoked exactly once
+        assert_eq!(receiver.recv(), Ok(Some(7)), "Callback has invalid result");
+        assert!(receiver.recv().is_err(), "Callback has been invoked more than once");
+    }
+}
+
+#[test]
+fn timeout_remaining() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+
+    // Await the result and check the remaining budget
+    let (result, remaining) =
+        getter.wait_timeout_remaining(Duration::from_secs(2)).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+    assert!(remaining < Duration::from_millis(1900), "Remaining budget is too large: {remaining:?}");
+    assert!(remaining > Duration::from_secs(1), "Remaining budget is too small: {remaining:?}");
+}
+
+#[test]
+fn timeout_remaining_boundary() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+
+    // A zero budget must report a zero remainder instead of panicking
+    let (result, remaining) = getter.wait_timeout_remaining(Duration::ZERO).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+    assert_eq!(remaining, Duration::ZERO, "Remaining budget is invalid");
+}
+
+#[test]
+fn heartbeat_success() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Set the result after a few ticks
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(250));
+        setter.set(7);
+    });
+
+    // Count the ticks while waiting
+    let mut ticks = 0;
+    let outcome = getter.wait_with_heartbeat(Duration::from_millis(100), || {
+        ticks += 1;
+        ControlFlow::Continue(())
+    });
+    assert!(matches!(outcome, InterruptOutcome::Ready(7)), "Future has invalid outcome: {outcome:?}");
+    assert_eq!(ticks, 2, "Heartbeat has been invoked an invalid amount of times");
+}
+
+#[test]
+fn heartbeat_abort() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Abort the wait on the second tick
+    let mut ticks = 0;
+    let outcome = getter.wait_with_heartbeat(Duration::from_millis(50), || {
+        ticks += 1;
+        match ticks {
+            2 => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    });
+
+    // The getter must be handed back and remain usable
+    let InterruptOutcome::Interrupted(getter) = outcome else {
+        panic!("Wait has not been aborted");
+    };
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn heartbeat_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Drop the setter between two ticks
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(setter);
+    });
+
+    // The cancellation must be observed immediately
+    let start = Instant::now();
+    let outcome = getter.wait_with_heartbeat(Duration::from_secs(10), || ControlFlow::Continue(()));
+    assert!(matches!(outcome, InterruptOutcome::Cancelled), "Future has invalid outcome: {outcome:?}");
+    assert!(start.elapsed() < Duration::from_secs(1), "Cancellation has not been observed between ticks");
+}
+
+#[test]
+fn take_if_ready() {
+    let (setter, mut getter) = tiny_future::new::<u8>();
+
+    // The future is still pending
+    assert_eq!(getter.take_if_ready(), None, "Future has been resolved too early");
+    setter.set(7);
+
+    // Claim the result; afterwards the getter must report a terminal state
+    assert_eq!(getter.take_if_ready(), Some(Some(7)), "Future has invalid result");
+    assert_eq!(getter.take_if_ready(), Some(None), "Future does not report a terminal state");
+    assert_eq!(getter.wait(), None, "Future does not report a terminal state");
+}
+
+#[test]
+fn take_if_ready_retained() {
+    let (setters, mut getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<u8>()).unzip();
+    let [a, b, c]: [_; 3] = setters.try_into().expect("Invalid amount of setters");
+
+    // Harvest the results across several frames
+    a.set(1);
+    let frame: Vec<_> = getters.iter_mut().map(|getter| getter.take_if_ready()).collect();
+    assert_eq!(frame, vec![Some(Some(1)), None, None], "Frame has invalid results");
+
+    drop(b);
+    c.set(3);
+    let frame: Vec<_> = getters.iter_mut().skip(1).map(|getter| getter.take_if_ready()).collect();
+    assert_eq!(frame, vec![Some(None), Some(Some(3))], "Frame has invalid results");
+}
+
+#[test]
+fn wait_ref() {
+    let (setter, getter) = tiny_future::new::<Vec<u8>>();
+
+    // Set the result after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(vec![7; 1024]);
+    });
+
+    // Borrow the result several times without consuming it
+    for _ in 0..3 {
+        let result = getter.wait_ref().expect("Future has been cancelled");
+        assert_eq!(result.len(), 1024, "Future has invalid result");
+    }
+    assert!(getter.is_ready(), "Borrowing has consumed the result");
+    assert_eq!(getter.wait().map(|result| result.len()), Some(1024), "Future has invalid result");
+}
+
+#[test]
+fn wait_ref_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    drop(setter);
+    assert!(getter.wait_ref().is_none(), "Future has not been cancelled");
+}
+
+#[test]
+fn cancel_after() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let start = Instant::now();
+
+    // Schedule the cancellation
+    let _scheduled = getter.cancel_after(Duration::from_millis(200));
+    assert!(!setter.is_cancelled(), "Future has been cancelled too early");
+
+    // A blocked waiter must be woken by the cancellation
+    let waiter = thread::spawn(move || getter.wait());
+    assert_eq!(waiter.join().expect("Waiter thread panicked"), None, "Future has not been cancelled");
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    assert!(start.elapsed() >= Duration::from_millis(200), "Future has been cancelled too early");
+    assert!(start.elapsed() < Duration::from_secs(2), "Future has been cancelled too late");
+}
+
+#[test]
+fn cancel_after_abort() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Abort the scheduled cancellation
+    let scheduled = getter.cancel_after(Duration::from_millis(100));
+    assert!(scheduled.abort(), "Scheduled cancellation was not pending");
+    assert!(!scheduled.abort(), "Scheduled cancellation has been aborted twice");
+
+    // The future must not be cancelled
+    thread::sleep(Duration::from_millis(200));
+    assert!(!setter.is_cancelled(), "Future has been cancelled despite the abort");
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn cancel_after_completed() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Complete the future before the scheduled cancellation fires
+    let _scheduled = getter.cancel_after(Duration::from_millis(100));
+    setter.set(7);
+    thread::sleep(Duration::from_millis(200));
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn inspect() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+
+    // Stack several inspectors
+    let (sender_a, sender_b) = (sender.clone(), sender.clone());
+    let getter = getter
+        .inspect(move |value| sender_a.send(("a", *value)).expect("Receiver is dead?!"))
+        .inspect(move |value| sender_b.send(("b", *value)).expect("Receiver is dead?!"));
+    drop(sender);
+
+    // The inspectors must not run before the result is claimed
+    setter.set(7);
+    assert!(receiver.try_recv().is_err(), "Inspector has run before the result has been claimed");
+
+    // The inspectors must run exactly once in registration order
+    let waiter = thread::spawn(move || getter.wait());
+    assert_eq!(waiter.join().expect("Waiter thread panicked"), Some(7), "Future has invalid result");
+    assert_eq!(receiver.iter().collect::<Vec<_>>(), vec![("a", 7), ("b", 7)], "Inspectors have run invalidly");
+}
+
+#[test]
+fn inspect_try_get() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+    let getter = getter.inspect(move |value| sender.send(*value).expect("Receiver is dead?!"));
+
+    // A pending poll must not run the inspector
+    let getter = getter.try_get().expect_err("Future has been resolved too early");
+    assert!(receiver.try_recv().is_err(), "Inspector has run on a pending poll");
+
+    // A successful poll must run the inspector
+    setter.set(7);
+    assert_eq!(getter.try_get().expect("Future is still pending"), Some(7), "Future has invalid result");
+    assert_eq!(receiver.recv(), Ok(7), "Inspector has not run");
+}
+
+#[test]
+fn inspect_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let getter = getter.inspect(|_| panic!("Inspector has run on cancellation"));
+    drop(setter);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn id() {
+    let (setter_a, getter_a) = tiny_future::new::<u8>();
+    let (setter_b, getter_b) = tiny_future::new::<u8>();
+
+    // Both halves must share the same identity which is distinct from other futures
+    assert_eq!(setter_a.id(), getter_a.id(), "Setter and getter have different identities");
+    assert_eq!(setter_b.id(), getter_b.id(), "Setter and getter have different identities");
+    assert_ne!(getter_a.id(), getter_b.id(), "Distinct futures have the same identity");
+
+    // The identity must be usable as map key
+    let ids = HashMap::from([(getter_a.id(), "a"), (getter_b.id(), "b")]);
+    assert_eq!(ids.get(&setter_a.id()), Some(&"a"), "Identity is not usable as map key");
+
+    // The identity must be part of the debug representation
+    let id = format!("{:?}", getter_a.id());
+    assert!(format!("{setter_a:?}").contains(&id), "Setter debug representation does not contain the identity");
+    assert!(format!("{getter_a:?}").contains(&id), "Getter debug representation does not contain the identity");
+}
+
+#[test]
+fn has_setter() {
+    // The setter must be live until it is dropped
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert!(getter.has_setter(), "Future has no live setter");
+    drop(setter);
+    assert!(!getter.has_setter(), "Future has a live setter after it has been dropped");
+    assert_eq!(getter.wait_result(), Err(Cancelled::SetterDropped), "Future has not been cancelled");
+
+    // The setter must be gone once it has been consumed
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set(7);
+    assert!(!getter.has_setter(), "Future has a live setter after it has been consumed");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn set_cancel_race() {
+    for _ in 0..1000 {
+        let (setter, getter) = tiny_future::new::<u8>();
+        let barrier = Arc::new(Barrier::new(2));
+
+        // Race the setter against the cancellation
+        let setter = {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                setter.set(7);
+            })
+        };
+        barrier.wait();
+        getter.cancel();
+        setter.join().expect("Setter thread panicked");
+
+        // Exactly one terminal state must be observed consistently by all APIs
+        match getter.state() {
+            FutureState::Ready => {
+                assert!(!getter.is_cancelled(), "Resolved future has been cancelled");
+                assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+            }
+            FutureState::Cancelled => {
+                assert!(getter.is_cancelled(), "Cancelled future is not flagged as cancelled");
+                assert_eq!(getter.wait(), None, "Cancelled future has a result");
+            }
+            FutureState::Pending => panic!("Future is still pending"),
+        }
+    }
+}
+
+#[test]
+fn try_set() {
+    // Setting a pending future must succeed
+    let (setter, getter) = tiny_future::new::<String>();
+    assert_eq!(setter.try_set("seven".to_string()), Ok(()), "Setting a pending future has failed");
+    assert_eq!(getter.wait().as_deref(), Some("seven"), "Future has invalid result");
+
+    // Setting a cancelled future must hand the value back
+    let (setter, getter) = tiny_future::new::<String>();
+    drop(getter);
+    let error = setter.try_set("seven".to_string()).expect_err("Setting a cancelled future has succeeded");
+    assert_eq!(error, SetError::Cancelled { value: "seven".to_string(), reason: Cancelled::Explicit }, "Invalid error");
+    assert_eq!(error.into_inner(), "seven", "Error has invalid value");
+}
+
+#[test]
+fn try_set_cancel_race() {
+    for _ in 0..1000 {
+        let (setter, getter) = tiny_future::new::<u8>();
+        let barrier = Arc::new(Barrier::new(2));
+
+        // Race the setter against the cancellation
+        let setter = {
+            let barrier = barrier.clone();
+            thread::spawn(move || {
+                barrier.wait();
+                setter.try_set(7)
+            })
+        };
+        barrier.wait();
+        getter.cancel();
+        let result = setter.join().expect("Setter thread panicked");
+
+        // The value must either be delivered or handed back
+        match result {
+            Ok(()) => assert_eq!(getter.wait(), Some(7), "Delivered value is missing"),
+            Err(error) => {
+                assert_eq!(error.into_inner(), 7, "Error has invalid value");
+                assert_eq!(getter.wait(), None, "Returned value has been delivered too");
+            }
+        }
+    }
+}
+
+#[test]
+fn set_with() {
+    // The value must be computed and set if the future is pending
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.set_with(|| 7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+
+    // The closure must not be invoked if the future has been cancelled
+    let (setter, getter) = tiny_future::new::<u8>();
+    drop(getter);
+    setter.set_with(|| panic!("Value has been computed for a cancelled future"));
+}
+
+#[test]
+fn set_with_cancel_during_computation() {
+    /// A value that records when it is dropped
+    struct Tracked(Arc<AtomicBool>);
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.store(true, SeqCst);
+        }
+    }
+
+    // Cancel the future while the value is being computed
+    let (setter, getter) = tiny_future::new::<Tracked>();
+    let dropped = Arc::new(AtomicBool::new(false));
+    let dropped_ = dropped.clone();
+    setter.set_with(move || {
+        drop(getter);
+        Tracked(dropped_)
+    });
+
+    // The computed value must have been dropped deterministically once the cancellation has won the race
+    assert!(dropped.load(SeqCst), "Computed value has not been dropped");
+}
+
+#[test]
+fn has_waiter() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert!(!setter.has_waiter(), "Future has a waiter before anybody waits");
+
+    // Block on the future on another thread until the wait times out
+    let waiter = thread::spawn(move || getter.wait_timeout(Duration::from_millis(200)).expect_err("Future is resolved"));
+    while !setter.has_waiter() {
+        thread::sleep(Duration::from_millis(10));
+    }
+    assert_eq!(setter.waiter_count(), 1, "Future has invalid waiter count");
+
+    // The waiter must be unregistered once the wait is over, even though the getter still exists
+    let getter = waiter.join().expect("Waiter thread panicked");
+    assert!(!setter.has_waiter(), "Future has a waiter after the wait is over");
+    assert!(!getter.is_cancelled(), "Future has been cancelled");
+}
+
+#[test]
+fn wait_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Park a supervisor on the cancellation and drop the getter after a short delay
+    let setter = Arc::new(setter);
+    let supervisor = {
+        let setter = setter.clone();
+        thread::spawn(move || setter.wait_cancelled())
+    };
+    thread::sleep(Duration::from_millis(100));
+    assert!(!supervisor.is_finished(), "Supervisor has returned before the cancellation");
+    drop(getter);
+    supervisor.join().expect("Supervisor thread panicked");
+
+    // Waiting on an already cancelled future must return immediately
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    setter.wait_cancelled();
+}
+
+#[test]
+fn wait_cancelled_explicit() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        getter.cancel();
+    });
+    setter.wait_cancelled();
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn wait_cancelled_timeout() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // The wait must time out while the future is pending
+    assert!(!setter.wait_cancelled_timeout(Duration::from_millis(50)), "Future has been cancelled");
+
+    // The wait must return promptly once the cancellation arrives
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        getter.cancel();
+    });
+    let start = Instant::now();
+    assert!(setter.wait_cancelled_timeout(Duration::from_secs(10)), "Future has not been cancelled");
+    assert!(start.elapsed() < Duration::from_secs(5), "Wait has not returned promptly");
+}
+
+#[test]
+fn wait_cancelled_on_complete() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (result_tx, result_rx) = mpsc::channel();
+    getter.on_complete(move |result| result_tx.send(result).expect("Failed to send result"));
+
+    // A supervisor on a cloned setter must return once the other setter sets the result
+    let supervisor = {
+        let setter = setter.clone();
+        thread::spawn(move || setter.wait_cancelled_timeout(Duration::from_secs(10)))
+    };
+    thread::sleep(Duration::from_millis(100));
+    let start = Instant::now();
+    setter.set(7);
+    let cancelled = supervisor.join().expect("Supervisor thread panicked");
+    assert!(start.elapsed() < Duration::from_secs(5), "Wait has not returned promptly");
+    assert!(!cancelled, "Future has been reported as cancelled although it has been set");
+    assert_eq!(result_rx.recv().ok(), Some(Some(7)), "Callback has invalid result");
+}
+
+#[test]
+fn cloned_setter() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (clone_a, clone_b) = (setter.clone(), setter.clone());
+
+    // Dropping some of the clones must not cancel the future
+    drop(setter);
+    drop(clone_a);
+    assert!(!clone_b.is_cancelled(), "Future has been cancelled by dropping a clone");
+    assert!(getter.has_setter(), "Future has no live setter");
+
+    // Dropping the last clone must cancel the future
+    drop(clone_b);
+    assert_eq!(getter.wait_result(), Err(Cancelled::SetterDropped), "Future has not been cancelled");
+}
+
+#[test]
+fn cloned_setter_first_set_wins() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let clone = setter.clone();
+    assert_eq!(setter.try_set(7), Ok(()), "First set has failed");
+    assert_eq!(clone.try_set(4), Err(SetError::AlreadySet(4)), "Second set has not been rejected");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn cloned_setter_race() {
+    for _ in 0..100 {
+        let (setter, getter) = tiny_future::new::<usize>();
+        let barrier = Arc::new(Barrier::new(8));
+
+        // Race many clones against each other
+        let racers: Vec<_> = (0..8)
+            .map(|index| {
+                let (setter, barrier) = (setter.clone(), barrier.clone());
+                thread::spawn(move || {
+                    barrier.wait();
+                    setter.try_set(index).is_ok()
+                })
+            })
+            .collect();
+        drop(setter);
+
+        // Exactly one value must be delivered
+        let winners: Vec<_> = racers.into_iter().map(|racer| racer.join().expect("Racer thread panicked")).collect();
+        let winner = winners.iter().position(|won| *won).expect("No setter has won");
+        assert_eq!(winners.iter().filter(|won| **won).count(), 1, "Several setters have won");
+        assert_eq!(getter.wait(), Some(winner), "Future has invalid result");
+    }
+}
+
+#[test]
+fn abandon() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Abandoning the setter must neither cancel the future nor count as live setter
+    setter.abandon();
+    assert_eq!(getter.state(), FutureState::Pending, "Future has been resolved");
+    assert!(!getter.has_setter(), "Abandoned setter is still live");
+
+    // The getter must still be able to time out on its own
+    let getter = getter.wait_timeout(Duration::from_millis(50)).expect_err("Future has been resolved");
+    assert!(!getter.is_cancelled(), "Future has been cancelled");
+}
+
+#[test]
+fn abandon_clone() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let clone = setter.clone();
+
+    // Abandoning one clone must not keep the other clone from completing the future
+    clone.abandon();
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn abandon_checked() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let clone = setter.clone();
+
+    // Abandon every setter while the getter is waiting
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        clone.abandon();
+        setter.abandon();
+    });
+
+    // The checked wait must detect that the future can never complete
+    let start = Instant::now();
+    assert_eq!(getter.wait_checked(), Err(Cancelled::NoSetter), "Future has invalid result");
+    assert!(start.elapsed() < Duration::from_secs(1), "Abandoned setter has not been detected");
+}
+
+#[test]
+fn abandon_checked_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let clone = setter.clone();
+
+    // A live setter must still be able to complete the future
+    clone.abandon();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set(7);
+    });
+    assert_eq!(getter.wait_checked(), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn complete_with() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.complete_with(|| 7);
+    assert!(matches!(getter.wait_outcome(), JoinOutcome::Ready(7)), "Future has invalid outcome");
+}
+
+#[test]
+fn complete_with_panic() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.complete_with(|| panic!("producer failed"));
+
+    // The future must not be poisoned, and must look cancelled to other operations
+    assert_eq!(getter.state(), FutureState::Cancelled, "Future has invalid state");
+    assert!(getter.is_cancelled(), "Future has not been cancelled");
+
+    // The panic payload must be delivered to the consumer
+    let JoinOutcome::Panicked(payload) = getter.wait_outcome() else {
+        panic!("Future has not panicked");
+    };
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"producer failed"), "Panic has invalid payload");
+}
+
+#[test]
+fn complete_with_panic_after_timeout() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel::<()>();
+    thread::spawn(move || {
+        setter.complete_with(|| {
+            receiver.recv().expect("Sender is dead?!");
+            panic!("producer failed")
+        })
+    });
+
+    // The consumer must time out before the panic is delivered
+    let WaitOutcome::TimedOut(getter) = getter.wait_timeout_outcome(Duration::from_millis(50)) else {
+        panic!("Future has not timed out");
+    };
+
+    // The panic must be delivered once it occurs
+    sender.send(()).expect("Receiver is dead?!");
+    let outcome = getter.wait_outcome();
+    assert!(matches!(outcome, JoinOutcome::Panicked(_)), "Future has invalid outcome: {outcome:?}");
+}
+
+#[test]
+fn complete_with_panic_result() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.complete_with(|| panic!("producer failed"));
+    assert_eq!(getter.wait_result(), Err(Cancelled::Panicked), "Future has invalid result");
+}
+
+#[test]
+fn forward() {
+    let (source_setter, source) = tiny_future::new::<u8>();
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.forward(source);
+
+    // The result must be forwarded
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        source_setter.set(7);
+    });
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn forward_source_cancelled() {
+    let (source_setter, source) = tiny_future::new::<u8>();
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.forward(source);
+
+    // Cancelling the source must cancel the destination
+    drop(source_setter);
+    assert_eq!(getter.wait(), None, "Destination has not been cancelled");
+}
+
+#[test]
+fn forward_destination_cancelled() {
+    let (source_setter, source) = tiny_future::new::<u8>();
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.forward(source);
+
+    // Cancelling the destination must propagate back to the source
+    assert!(!source_setter.is_cancelled(), "Source has been cancelled too early");
+    getter.cancel();
+    assert!(source_setter.is_cancelled(), "Source has not been cancelled");
+}
+
+#[test]
+fn weak_setter() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let weak = setter.downgrade();
+
+    // A weak handle must be upgradable while a setter is live
+    let upgraded = weak.upgrade().expect("Weak setter cannot be upgraded");
+    drop(setter);
+    assert!(!getter.is_cancelled(), "Future has been cancelled while an upgraded setter is live");
+    upgraded.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn weak_setter_does_not_keep_alive() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let weak = setter.downgrade();
+
+    // Dropping the last strong setter must cancel the future even though a weak handle remains
+    drop(setter);
+    assert!(weak.upgrade().is_none(), "Weak setter has been upgraded after the last setter has been dropped");
+    assert_eq!(getter.wait_result(), Err(Cancelled::SetterDropped), "Future has not been cancelled");
+}
+
+#[test]
+fn drop_fallback() {
+    // Dropping the setter must deliver the fallback
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.on_drop_complete_with(7);
+    drop(setter);
+    assert_eq!(getter.wait(), Some(7), "Fallback has not been delivered");
+
+    // The default fallback must be delivered too
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.on_drop_complete_with_default();
+    drop(setter);
+    assert_eq!(getter.wait(), Some(0), "Default fallback has not been delivered");
+}
+
+#[test]
+fn drop_fallback_explicit() {
+    // The fallback must not override an explicit set
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.on_drop_complete_with(7);
+    setter.set(4);
+    assert_eq!(getter.wait(), Some(4), "Fallback has overridden the result");
+
+    // The fallback must not override an explicit cancellation
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.on_drop_complete_with(7);
+    setter.cancel();
+    drop(setter);
+    assert_eq!(getter.wait(), None, "Fallback has overridden the cancellation");
+}
+
+#[test]
+fn drop_fallback_panic() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    setter.on_drop_complete_with(7);
+
+    // The fallback must be delivered while unwinding from a panic in the producer
+    let producer = thread::spawn(move || {
+        let _setter = setter;
+        panic!("producer failed");
+    });
+    assert!(producer.join().is_err(), "Producer has not panicked");
+    assert_eq!(getter.wait(), Some(7), "Fallback has not been delivered");
+}
+
+#[test]
+fn on_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let (sender, receiver) = mpsc::channel();
+
+    // The callback must be invoked exactly once when the getter is dropped
+    setter.on_cancelled(move || sender.send(()).expect("Receiver is dead?!"));
+    assert!(receiver.try_recv().is_err(), "Callback has been invoked too early");
+    drop(getter);
+    assert_eq!(receiver.iter().count(), 1, "Callback has not been invoked exactly once");
+}
+
+#[test]
+fn on_cancelled_already_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let invoked = Arc::new(AtomicBool::new(false));
+
+    // The callback must be invoked immediately if the future has already been cancelled
+    getter.cancel();
+    let invoked_ = invoked.clone();
+    setter.on_cancelled(move || invoked_.store(true, SeqCst));
+    assert!(invoked.load(SeqCst), "Callback has not been invoked immediately");
+}
+
+#[test]
+fn on_cancelled_completed() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // The callback must not be invoked if the future completes
+    setter.on_cancelled(|| panic!("Callback has been invoked on completion"));
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn set_if_waiting() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Without a waiting thread, the setter and the value must be handed back
+    let (setter, value) = setter.set_if_waiting(7).expect_err("Value has been delivered without a waiter");
+    assert_eq!(value, 7, "Value has been changed");
+    assert_eq!(getter.state(), FutureState::Pending, "Future has been resolved");
+
+    // With a waiting thread, the value must be delivered
+    let waiter = thread::spawn(move || getter.wait());
+    while !setter.has_waiter() {
+        thread::sleep(Duration::from_millis(10));
+    }
+    setter.set_if_waiting(7).expect("Value has not been delivered to the waiter");
+    assert_eq!(waiter.join().expect("Waiter thread panicked"), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn getter_alive() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert!(setter.is_getter_alive(), "Getter is not alive");
+
+    // Detaching consumes the getter without cancelling the future
+    getter.detach();
+    assert!(!setter.is_getter_alive(), "Getter is still alive");
+    assert!(!setter.is_cancelled(), "Future has been cancelled");
+}
+
+#[test]
+fn getter_alive_claimed() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let setter2 = setter.clone();
+
+    // Claiming the result consumes the getter
+    setter.set(7);
+    assert!(setter2.is_getter_alive(), "Getter is not alive");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+    assert!(!setter2.is_getter_alive(), "Getter is still alive");
+}
+
+#[test]
+fn setter_state() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let setter2 = setter.clone();
+    assert_eq!(setter.state(), FutureState::Pending, "Future has invalid state");
+    assert!(!setter.is_set(), "Future has been set too early");
+
+    // The setter must see the result as set even after it has been claimed
+    setter.set(7);
+    assert!(setter2.is_set(), "Future has not been set");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+    assert_eq!(setter2.state(), FutureState::Ready, "Future has invalid state");
+}
+
+#[test]
+fn setter_state_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    drop(getter);
+    assert_eq!(setter.state(), FutureState::Cancelled, "Future has invalid state");
+    assert!(!setter.is_set(), "Future has been set");
+}
+
+#[test]
+fn try_cancel_outcome() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    assert_eq!(setter.try_cancel(), CancelOutcome::Cancelled, "Future has not been cancelled");
+    assert_eq!(setter.try_cancel(), CancelOutcome::AlreadyCancelled, "Future has not been cancelled before");
+    assert_eq!(getter.try_cancel(), CancelOutcome::AlreadyCancelled, "Future has not been cancelled before");
+}
+
+#[test]
+fn try_cancel_already_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let setter2 = setter.clone();
+
+    // A cancellation after the result has been set or claimed must not matter
+    setter.set(7);
+    assert_eq!(getter.try_cancel(), CancelOutcome::AlreadySet, "Future has not been set before");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+    assert_eq!(setter2.try_cancel(), CancelOutcome::AlreadySet, "Future has not been set before");
+}
+
+#[test]
+fn ready() {
+    let getter = tiny_future::ready(7u8);
+    assert!(!getter.is_cancelled(), "Future has been cancelled");
+    assert!(!getter.has_setter(), "Future has a setter");
+    assert_eq!(getter.state(), FutureState::Ready, "Future has invalid state");
+
+    // A resolved future must never time out
+    let result = getter.wait_timeout(Duration::ZERO).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn from_value() {
+    let getter: Getter<u8> = 7.into();
+    assert!(!getter.has_setter(), "Future has a setter");
+    assert_eq!(getter.try_get().ok(), Some(Some(7)), "Future has invalid result");
+
+    // A converted future must never time out
+    let getter = Getter::from(7u8);
+    let result = getter.wait_timeout(Duration::ZERO).expect("Future has not been set in time");
+    assert_eq!(result, Some(7), "Future has invalid result");
+    assert_eq!(Getter::from(7u8).wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn from_result() {
+    let getter: Getter<Result<u8, &str>> = Ok(7).into();
+    assert_eq!(getter.wait(), Some(Ok(7)), "Future has invalid result");
+    let getter: Getter<Result<u8, &str>> = Err("failed").into();
+    assert_eq!(getter.wait_ok(), Err(FutureError::Failed("failed")), "Future has invalid result");
+}
+
+#[test]
+fn cancelled() {
+    let getter = tiny_future::cancelled::<u8>();
+    assert!(getter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(getter.state(), FutureState::Cancelled, "Future has invalid state");
+
+    // A resolved future must never time out
+    let result = getter.wait_timeout(Duration::ZERO).expect("Future has not been cancelled in time");
+    assert_eq!(result, None, "Future has not been cancelled");
+}
+
+#[test]
+fn lazy() {
+    let caller = thread::current().id();
+    let getter = tiny_future::lazy(move || {
+        assert_eq!(thread::current().id(), caller, "Closure does not run on the waiting thread");
+        7u8
+    });
+
+    // The closure must not run before the first wait
+    assert_eq!(getter.state(), FutureState::Pending, "Future has been evaluated too early");
+    let result = getter.wait_timeout(Duration::ZERO).expect("Future has not been evaluated");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn lazy_wait_ref() {
+    let getter = tiny_future::lazy(|| 7u8);
+    assert_eq!(getter.wait_ref().as_deref(), Some(&7), "Future has invalid result");
+
+    // The result must be stored like a normal completion
+    assert_eq!(getter.state(), FutureState::Ready, "Future has invalid state");
+    assert_eq!(getter.try_get().expect("Future is still pending"), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn lazy_try_get() {
+    let evaluated = Arc::new(AtomicBool::new(false));
+    let getter = {
+        let evaluated = evaluated.clone();
+        tiny_future::lazy(move || {
+            evaluated.store(true, SeqCst);
+            7u8
+        })
+    };
+
+    // A non-blocking check must not evaluate the closure
+    let getter = getter.try_get().expect_err("Future has been evaluated");
+    assert!(!evaluated.load(SeqCst), "Closure has been evaluated by a non-blocking check");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn lazy_spin() {
+    let getter = tiny_future::lazy(|| 7u8);
+
+    // The closure must be evaluated before spinning instead of after the spin period
+    let start = Instant::now();
+    assert_eq!(getter.wait_spin(Duration::from_secs(10)), Some(7), "Future has invalid result");
+    assert!(start.elapsed() < Duration::from_secs(1), "Closure has not been evaluated before spinning");
+}
+
+#[test]
+fn lazy_cancelled() {
+    let evaluated = Arc::new(AtomicBool::new(false));
+    let getter = {
+        let evaluated = evaluated.clone();
+        tiny_future::lazy(move || evaluated.store(true, SeqCst))
+    };
+
+    // Cancelling the future must prevent the closure from ever running
+    getter.cancel();
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert!(!evaluated.load(SeqCst), "Closure has been evaluated despite cancellation");
+}
+
+#[test]
+fn lazy_panic() {
+    let getter = tiny_future::lazy::<u8, _>(|| panic!("evaluation failed"));
+    assert!(matches!(getter.wait_outcome(), JoinOutcome::Panicked(_)), "Panic has not been delivered");
+}
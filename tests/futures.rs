@@ -0,0 +1,119 @@
+#![cfg(feature = "futures-compat")]
+
+use futures::{executor, future::FusedFuture, select, task::noop_waker, StreamExt, TryFutureExt};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    thread,
+    time::Duration,
+};
+use tiny_future::{Cancelled, FutureError};
+
+#[test]
+fn select_timer() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut getter = getter.into_async();
+    let mut timer = tiny_future::after(Duration::from_millis(100)).into_async();
+
+    // The timer must fire first
+    let timed_out = executor::block_on(async {
+        select! {
+            _ = getter => false,
+            _ = timer => true,
+        }
+    });
+    assert!(timed_out, "Timer has not fired");
+    assert!(FusedFuture::is_terminated(&timer), "Timer is not terminated");
+    assert!(!FusedFuture::is_terminated(&getter), "Future has been terminated too early");
+
+    // Complete the getter and select again; the terminated timer must be skipped
+    thread::spawn(move || setter.set(7));
+    let result = executor::block_on(async {
+        select! {
+            result = getter => result,
+            _ = timer => unreachable!("Terminated timer has been polled"),
+        }
+    });
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn select_terminated() {
+    let mut first = tiny_future::ready(4u8).into_async();
+    let (setter, second) = tiny_future::new::<u8>();
+    let mut second = second.into_async();
+
+    // Consume the first getter
+    let result = executor::block_on(async {
+        select! {
+            result = first => result,
+            result = second => result,
+        }
+    });
+    assert_eq!(result, Some(4), "Future has invalid result");
+    assert!(FusedFuture::is_terminated(&first), "Future is not terminated");
+
+    // Select over both getters again; only the second one may resolve
+    thread::spawn(move || setter.set(7));
+    let result = executor::block_on(async {
+        select! {
+            _ = first => unreachable!("Terminated future has been polled"),
+            result = second => result,
+        }
+    });
+    assert_eq!(result, Some(7), "Future has invalid result");
+    assert!(FusedFuture::is_terminated(&second), "Future is not terminated");
+}
+
+#[test]
+fn repoll_terminated() {
+    let mut getter = tiny_future::ready(7u8).into_async();
+    let waker = noop_waker();
+    let mut cx = Context::from_waker(&waker);
+
+    // Poll the getter to completion and poll again
+    assert_eq!(Pin::new(&mut getter).poll(&mut cx), Poll::Ready(Some(7)), "Future has invalid result");
+    assert!(FusedFuture::is_terminated(&getter), "Future is not terminated");
+    assert_eq!(Pin::new(&mut getter).poll(&mut cx), Poll::Pending, "Terminated future has resolved again");
+    assert!(FusedFuture::is_terminated(&getter), "Future is not terminated");
+}
+
+#[test]
+fn try_future() {
+    let (setter, getter) = tiny_future::new::<Result<u8, &str>>();
+    thread::spawn(move || setter.set(Ok(7)));
+    let result = executor::block_on(getter.into_try_async().map_ok(|value| value * 2));
+    assert_eq!(result, Ok(14), "Future has invalid result");
+
+    // Map failure and cancellation
+    let getter = tiny_future::ready(Err::<u8, _>("failed"));
+    let result = executor::block_on(getter.into_try_async());
+    assert_eq!(result, Err(FutureError::Failed("failed")), "Future has invalid result");
+    let (setter, getter) = tiny_future::new::<Result<u8, &str>>();
+    drop(setter);
+    let result = executor::block_on(getter.into_try_async());
+    assert_eq!(result, Err(FutureError::Cancelled(Cancelled::SetterDropped)), "Future has not been cancelled");
+}
+
+#[test]
+fn stream_next() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut stream = getter.into_stream();
+
+    // The stream must yield the result once and end afterwards
+    thread::spawn(move || setter.set(7));
+    let (first, second) = executor::block_on(async { (stream.next().await, stream.next().await) });
+    assert_eq!(first, Some(7), "Stream has invalid item");
+    assert_eq!(second, None, "Stream has not ended");
+}
+
+#[test]
+fn stream_collect() {
+    let items: Vec<u8> = executor::block_on(tiny_future::ready(7).into_stream().collect());
+    assert_eq!(items, vec![7], "Stream has invalid items");
+
+    // A cancelled future must end the stream without an item
+    let items: Vec<u8> = executor::block_on(tiny_future::cancelled::<u8>().into_stream().collect());
+    assert!(items.is_empty(), "Stream has invalid items");
+}
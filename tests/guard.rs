@@ -0,0 +1,59 @@
+use std::thread;
+use tiny_future::JoinOutcome;
+
+#[test]
+fn guard_fallback() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Leaving the scope must complete the future with the fallback
+    {
+        let _guard = setter.guard(|| 7);
+    }
+    assert_eq!(getter.wait(), Some(7), "Fallback has not been delivered");
+}
+
+#[test]
+fn guard_complete() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let guard = setter.guard(|| panic!("Fallback has been computed despite completion"));
+    guard.complete(4);
+    assert_eq!(getter.wait(), Some(4), "Future has invalid result");
+}
+
+#[test]
+fn guard_defuse() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let guard = setter.guard(|| panic!("Fallback has been computed despite defusing"));
+
+    // Defusing must hand the setter back without completing the future
+    let setter = guard.defuse();
+    assert!(!getter.is_cancelled(), "Future has been cancelled");
+    drop(setter);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn guard_panic() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // The fallback must be delivered while unwinding from a panic
+    let producer = thread::spawn(move || {
+        let _guard = setter.guard(|| 7);
+        panic!("producer failed");
+    });
+    assert!(producer.join().is_err(), "Producer has not panicked");
+    assert_eq!(getter.wait(), Some(7), "Fallback has not been delivered");
+}
+
+#[test]
+fn guard_panicking_fallback() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // A fallback that panics during unwinding must not abort, and its panic must be delivered
+    let producer = thread::spawn(move || {
+        let _guard = setter.guard(|| panic!("fallback failed"));
+        panic!("producer failed");
+    });
+    assert!(producer.join().is_err(), "Producer has not panicked");
+    assert!(matches!(getter.wait_outcome(), JoinOutcome::Panicked(_)), "Fallback panic has not been delivered");
+}
@@ -0,0 +1,58 @@
+use std::{thread, time::Duration};
+use tiny_future::HandshakeResult;
+
+#[test]
+fn handshake_accept() {
+    let (offer, accept) = tiny_future::handshake::<u8>();
+    let acceptor = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        accept.accept()
+    });
+    assert_eq!(offer.offer(7), HandshakeResult::Accepted, "Offer has not been accepted");
+    assert_eq!(acceptor.join().expect("Acceptor thread panicked"), Some(7), "Acceptor has invalid value");
+}
+
+#[test]
+fn handshake_reject() {
+    let (offer, accept) = tiny_future::handshake::<u8>();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        accept.reject();
+    });
+    assert_eq!(offer.offer(7), HandshakeResult::Rejected, "Offer has not been rejected");
+}
+
+#[test]
+fn handshake_dropped() {
+    // Dropping the accepting side must reject the offer
+    let (offer, accept) = tiny_future::handshake::<u8>();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(accept);
+    });
+    assert_eq!(offer.offer(7), HandshakeResult::Rejected, "Offer has not been rejected");
+
+    // Dropping the offering side must unblock the accepting side
+    let (offer, accept) = tiny_future::handshake::<u8>();
+    drop(offer);
+    assert_eq!(accept.accept(), None, "Acceptor has received a value");
+}
+
+#[test]
+fn handshake_timeout() {
+    // A withdrawn offer must never be accepted
+    let (offer, accept) = tiny_future::handshake::<u8>();
+    assert_eq!(offer.offer_timeout(7, Duration::from_millis(50)), HandshakeResult::TimedOut, "Offer has not timed out");
+    assert_eq!(accept.accept(), None, "Acceptor has received a withdrawn value");
+
+    // The accepting side must time out if nothing is offered
+    let (_offer, accept) = tiny_future::handshake::<u8>();
+    assert_eq!(accept.accept_timeout(Duration::from_millis(50)), None, "Acceptor has received a value");
+
+    // A decision that arrives in time must be reported
+    let (offer, accept) = tiny_future::handshake::<u8>();
+    let acceptor = thread::spawn(move || accept.accept_timeout(Duration::from_secs(10)));
+    let result = offer.offer_timeout(7, Duration::from_secs(10));
+    assert_eq!(result, HandshakeResult::Accepted, "Offer has not been accepted");
+    assert_eq!(acceptor.join().expect("Acceptor thread panicked"), Some(7), "Acceptor has invalid value");
+}
@@ -0,0 +1,59 @@
+use std::{thread, time::Duration};
+use tiny_future::Getter;
+
+/// A struct that owns getters of different types
+struct Pending {
+    /// The first getter
+    a: Getter<u8>,
+    /// The second getter
+    b: Getter<String>,
+}
+impl Pending {
+    /// Joins the getters of the struct
+    fn join(self) -> (Option<u8>, Option<String>) {
+        tiny_future::join!(self.a, self.b)
+    }
+}
+
+#[test]
+fn join() {
+    let (setter_a, getter_a) = tiny_future::new::<u8>();
+    let (setter_b, getter_b) = tiny_future::new::<String>();
+    let (setter_c, getter_c) = tiny_future::new::<bool>();
+
+    // Resolve the futures after a short delay and in reverse order
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        drop(setter_c);
+        thread::sleep(Duration::from_millis(50));
+        setter_b.set("seven".to_string());
+        setter_a.set(7);
+    });
+
+    let result = tiny_future::join!(getter_a, getter_b, getter_c);
+    assert_eq!(result, (Some(7), Some("seven".to_string()), None), "Futures have invalid results");
+}
+
+#[test]
+fn join_fields() {
+    let (setter_a, a) = tiny_future::new();
+    let (setter_b, b) = tiny_future::new();
+    setter_a.set(7);
+    setter_b.set("seven".to_string());
+    assert_eq!(Pending { a, b }.join(), (Some(7), Some("seven".to_string())), "Futures have invalid results");
+}
+
+#[test]
+fn join_eight() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..8u8).map(|_| tiny_future::new::<u8>()).unzip();
+    for (value, setter) in setters.into_iter().enumerate() {
+        setter.set(value as u8);
+    }
+
+    // Join eight getters of the same type
+    let mut getters = getters.into_iter();
+    let mut next = || getters.next().expect("Getter is missing");
+    let result = tiny_future::join!(next(), next(), next(), next(), next(), next(), next(), next());
+    let expected = (Some(0), Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), Some(7));
+    assert_eq!(result, expected, "Futures have invalid results");
+}
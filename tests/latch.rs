@@ -0,0 +1,33 @@
+use std::{sync::Arc, thread, time::Duration};
+use tiny_future::CountdownLatch;
+
+#[test]
+fn latch() {
+    let latch = Arc::new(CountdownLatch::new(3));
+    assert_eq!(latch.current(), 3, "Latch has invalid count");
+
+    // Count down from separate threads
+    for i in 0..3 {
+        let latch = latch.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10 * i));
+            latch.count_down();
+        });
+    }
+    latch.wait();
+    assert_eq!(latch.current(), 0, "Latch has invalid count");
+}
+
+#[test]
+fn latch_timeout() {
+    let latch = CountdownLatch::new(2);
+    latch.count_down();
+    assert_eq!(latch.current(), 1, "Latch has invalid count");
+    assert!(!latch.wait_timeout(Duration::from_millis(10)), "Latch has been released too early");
+
+    // Extra count downs beyond zero must be a no-op
+    latch.count_down();
+    latch.count_down();
+    assert_eq!(latch.current(), 0, "Latch has invalid count");
+    assert!(latch.wait_timeout(Duration::ZERO), "Latch has not been released");
+}
@@ -0,0 +1,49 @@
+use std::{cell::RefCell, rc::Rc};
+use tiny_future::{local, Cancelled, FutureGetter, FutureSetter};
+
+/// Completes a future and claims its result via the shared traits
+fn roundtrip<T, S, G>(setter: S, getter: G, value: T) -> Option<T>
+where
+    S: FutureSetter<T>,
+    G: FutureGetter<T>,
+{
+    let getter = getter.try_get().err().expect("Future is not pending");
+    setter.set(value);
+    getter.try_get().ok().expect("Future is still pending")
+}
+
+#[test]
+fn local() {
+    let (setter, getter) = local::new::<Rc<RefCell<u8>>>();
+    let getter = getter.try_get().expect_err("Future is not pending");
+
+    // Set the result
+    assert!(!getter.is_ready(), "Future is not pending");
+    setter.set(Rc::new(RefCell::new(7)));
+    let result = getter.try_get().expect("Future is still pending").expect("Future has been cancelled");
+    assert_eq!(*result.borrow(), 7, "Future has invalid result");
+}
+
+#[test]
+fn local_cancelled() {
+    // Drop the setter
+    let (setter, getter) = local::new::<u8>();
+    drop(setter);
+    assert!(getter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(getter.try_get_result().ok(), Some(Err(Cancelled::SetterDropped)), "Future has invalid result");
+
+    // Drop the getter
+    let (setter, getter) = local::new::<u8>();
+    drop(getter);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    assert!(setter.try_set(7).is_err(), "Future has accepted a result");
+}
+
+#[test]
+fn local_traits() {
+    let (setter, getter) = local::new();
+    assert_eq!(roundtrip(setter, getter, 7), Some(7), "Future has invalid result");
+
+    let (setter, getter) = tiny_future::new();
+    assert_eq!(roundtrip(setter, getter, 7), Some(7), "Future has invalid result");
+}
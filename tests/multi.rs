@@ -0,0 +1,250 @@
+use std::{
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+use tiny_future::{JoinError, Selected};
+
+#[test]
+fn wait_any() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4).map(|_| tiny_future::new::<u8>()).unzip();
+
+    // Set the third result after a short delay
+    let mut setters = setters.into_iter().map(Some).collect::<Vec<_>>();
+    let setter = setters[2].take().expect("Setter is missing");
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+
+    // Wait until the third future resolves
+    let (index, result, getters) = tiny_future::wait_any(getters);
+    assert_eq!((index, result), (2, Some(7)), "Invalid future has been resolved");
+    assert_eq!(getters.len(), 3, "Remaining getters are invalid");
+
+    // The remaining getters must still be usable
+    setters[3].take().expect("Setter is missing").set(4);
+    let (index, result, getters) = tiny_future::wait_any(getters);
+    assert_eq!((index, result), (2, Some(4)), "Invalid future has been resolved");
+    assert_eq!(getters.len(), 2, "Remaining getters are invalid");
+    assert!(setters.iter().flatten().all(|setter| !setter.is_cancelled()), "Remaining futures have been cancelled");
+}
+
+#[test]
+fn wait_any_already_resolved() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4).map(|_| tiny_future::new::<u8>()).unzip();
+
+    // Resolve several futures before waiting
+    let mut setters = setters.into_iter();
+    let _first = setters.next();
+    setters.next().expect("Setter is missing").set(1);
+    drop(setters.next());
+    setters.next().expect("Setter is missing").set(3);
+
+    // The resolved future with the lowest index must be picked
+    let (index, result, getters) = tiny_future::wait_any(getters);
+    assert_eq!((index, result), (1, Some(1)), "Invalid future has been resolved");
+    let (index, result, _) = tiny_future::wait_any(getters);
+    assert_eq!((index, result), (1, None), "Invalid future has been resolved");
+}
+
+#[test]
+fn wait_any_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Drop the setter after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(setter);
+    });
+
+    // Wait until the future is cancelled
+    let (index, result, getters) = tiny_future::wait_any(vec![getter]);
+    assert_eq!((index, result), (0, None), "Future has not been cancelled");
+    assert!(getters.is_empty(), "Remaining getters are invalid");
+}
+
+#[test]
+fn wait_all() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4u8).map(|_| tiny_future::new::<u8>()).unzip();
+
+    // Resolve the futures in reverse order and cancel one of them
+    thread::spawn(move || {
+        for (index, setter) in setters.into_iter().enumerate().rev() {
+            thread::sleep(Duration::from_millis(20));
+            match index {
+                1 => drop(setter),
+                index => setter.set(index as u8),
+            }
+        }
+    });
+
+    // Wait until all futures are resolved
+    let results = tiny_future::wait_all(getters);
+    assert_eq!(results, vec![Some(0), None, Some(2), Some(3)], "Futures have invalid results");
+}
+
+#[test]
+fn wait_all_deadline() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..2).map(|_| tiny_future::new::<u8>()).unzip();
+    let mut setters = setters.into_iter();
+
+    // Resolve only one of the futures
+    setters.next().expect("Setter is missing").set(7);
+    let deadline = Instant::now() + Duration::from_millis(100);
+    let getters = tiny_future::wait_all_deadline(getters, deadline).expect_err("Futures have been resolved too early");
+    assert_eq!(getters.len(), 2, "Getters have not been handed back");
+
+    // Resolve the remaining future
+    setters.next().expect("Setter is missing").set(4);
+    let deadline = Instant::now() + Duration::from_millis(100);
+    let results = tiny_future::wait_all_deadline(getters, deadline).expect("Futures have not been resolved in time");
+    assert_eq!(results, vec![Some(7), Some(4)], "Futures have invalid results");
+}
+
+#[test]
+fn try_join_all() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4u8).map(|_| tiny_future::new::<u8>()).unzip();
+
+    // Resolve the futures in reverse order
+    thread::spawn(move || {
+        for (index, setter) in setters.into_iter().enumerate().rev() {
+            thread::sleep(Duration::from_millis(20));
+            setter.set(index as u8);
+        }
+    });
+
+    // Wait until all futures are resolved
+    let results = tiny_future::try_join_all(getters).expect("Futures have been cancelled");
+    assert_eq!(results, vec![0, 1, 2, 3], "Futures have invalid results");
+}
+
+#[test]
+fn try_join_all_cancelled() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4u8).map(|_| tiny_future::new::<u8>()).unzip();
+    let mut setters = setters.into_iter().map(Some).collect::<Vec<_>>();
+
+    // Cancel the third future after a short delay
+    let setter = setters[2].take().expect("Setter is missing");
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(setter);
+    });
+
+    // Wait until the cancellation short-circuits the join
+    let error = tiny_future::try_join_all(getters).expect_err("Join has not been cancelled");
+    assert_eq!(error, JoinError { index: 2 }, "Join error has invalid index");
+    assert!(setters.iter().flatten().all(|setter| setter.is_cancelled()), "Remaining futures have not been cancelled");
+}
+
+#[test]
+fn first_ok() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..4).map(|_| tiny_future::new::<Result<u8, &str>>()).unzip();
+    let [a, b, c, d]: [_; 4] = setters.try_into().expect("Invalid amount of setters");
+
+    // Fail several attempts before the winning `Ok` arrives
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        a.set(Err("a"));
+        thread::sleep(Duration::from_millis(20));
+        drop(b);
+        thread::sleep(Duration::from_millis(20));
+        d.set(Ok(7));
+    });
+
+    // Wait until the first `Ok` arrives; the loser must be cancelled
+    let result = tiny_future::first_ok(getters);
+    assert_eq!(result, Ok(7), "Future has invalid result");
+    assert!(c.is_cancelled(), "Loser has not been cancelled");
+}
+
+#[test]
+fn first_ok_all_failed() {
+    let (setters, getters): (Vec<_>, Vec<_>) = (0..3).map(|_| tiny_future::new::<Result<u8, &str>>()).unzip();
+
+    // Fail all attempts
+    thread::spawn(move || {
+        for (index, setter) in setters.into_iter().enumerate() {
+            thread::sleep(Duration::from_millis(20));
+            match index {
+                1 => drop(setter),
+                _ => setter.set(Err("failed")),
+            }
+        }
+    });
+
+    // Wait until all attempts have failed
+    let errors = tiny_future::first_ok(getters).expect_err("Future has not failed");
+    assert_eq!(errors, vec![Some("failed"), None, Some("failed")], "Future has invalid errors");
+}
+
+#[test]
+fn select2_loop() {
+    let (shutdown_setter, mut shutdown) = tiny_future::new::<()>();
+
+    // Produce a few data futures before shutting down
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for value in 0..3 {
+            let (setter, getter) = tiny_future::new::<u8>();
+            sender.send(getter).expect("Receiver is dead?!");
+            thread::sleep(Duration::from_millis(20));
+            setter.set(value);
+        }
+        thread::sleep(Duration::from_millis(20));
+        shutdown_setter.set(());
+    });
+
+    // Select between data and shutdown in a loop
+    let mut values = Vec::new();
+    for data in receiver.iter() {
+        match tiny_future::select2(data, shutdown) {
+            Selected::Left(value, pending) => {
+                values.push(value.expect("Data future has been cancelled"));
+                shutdown = pending;
+            }
+            Selected::Right(..) => panic!("Shutdown has been selected too early"),
+        }
+    }
+    assert_eq!(values, vec![0, 1, 2], "Data futures have invalid results");
+
+    // The shutdown must be selected once it arrives
+    let (_data_setter, data) = tiny_future::new::<u8>();
+    match tiny_future::select2(data, shutdown) {
+        Selected::Right(value, _) => assert_eq!(value, Some(()), "Shutdown has invalid result"),
+        Selected::Left(..) => panic!("Data has been selected instead of the shutdown"),
+    }
+}
+
+#[test]
+fn select2_cancelled() {
+    let (a_setter, a) = tiny_future::new::<u8>();
+    let (b_setter, b) = tiny_future::new::<&str>();
+
+    // A cancelled side must be reported, and the other side must be handed back untouched
+    drop(b_setter);
+    match tiny_future::select2(a, b) {
+        Selected::Right(value, a) => {
+            assert_eq!(value, None, "Cancelled side has a result");
+            assert!(!a_setter.is_cancelled(), "Pending side has been cancelled");
+            a_setter.set(7);
+            assert_eq!(a.wait(), Some(7), "Pending side has invalid result");
+        }
+        Selected::Left(..) => panic!("Pending side has been selected"),
+    }
+}
+
+#[test]
+fn lazy_multi() {
+    let result = tiny_future::wait_all(vec![tiny_future::lazy(|| 4u8), tiny_future::lazy(|| 7)]);
+    assert_eq!(result, vec![Some(4), Some(7)], "Futures have invalid results");
+
+    // Lazy futures must be evaluated by the other multi-waits too
+    let (index, result, _) = tiny_future::wait_any(vec![tiny_future::lazy(|| 7u8)]);
+    assert_eq!((index, result), (0, Some(7)), "Future has invalid result");
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let result = tiny_future::wait_all_deadline(vec![tiny_future::lazy(|| 7u8)], deadline).ok();
+    assert_eq!(result, Some(vec![Some(7)]), "Future has invalid result");
+    let result = tiny_future::first_ok(vec![tiny_future::lazy(|| Err::<u8, u8>(4)), tiny_future::lazy(|| Ok(7))]);
+    assert_eq!(result, Ok(7), "Future has invalid result");
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "futures-channel")]
+
+use futures::{
+    channel::oneshot::{self, Canceled},
+    executor,
+};
+use std::{thread, time::Duration};
+
+#[test]
+fn into_futures_oneshot() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let receiver = getter.into_futures_oneshot();
+
+    // Set the result from a plain thread and await it
+    thread::spawn(move || setter.set(7));
+    assert_eq!(executor::block_on(receiver), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn into_futures_oneshot_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut receiver = getter.into_futures_oneshot();
+    assert_eq!(receiver.try_recv(), Ok(None), "Future is not pending");
+
+    // Dropping the setter must resolve the receiver to `Canceled`
+    drop(setter);
+    assert_eq!(executor::block_on(receiver), Err(Canceled), "Channel has not been cancelled");
+}
+
+#[test]
+fn into_futures_oneshot_dropped() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let receiver = getter.into_futures_oneshot();
+    assert!(!setter.is_cancelled(), "Future has been cancelled too early");
+
+    // Dropping the receiver must cancel the future
+    drop(receiver);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn from_futures_oneshot() {
+    let (sender, receiver) = oneshot::channel::<u8>();
+    let getter = tiny_future::from_futures_oneshot(receiver);
+
+    // Send the value from a plain thread and wait for it
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        sender.send(7).expect("Failed to send value");
+    });
+    let result = getter.wait_timeout(Duration::from_secs(5)).expect("Future has timed out");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn from_futures_oneshot_cancelled() {
+    let (sender, receiver) = oneshot::channel::<u8>();
+    let getter = tiny_future::from_futures_oneshot(receiver);
+
+    // Dropping the sender must cancel the future
+    drop(sender);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn from_futures_oneshot_dropped() {
+    let (mut sender, receiver) = oneshot::channel::<u8>();
+    let getter = tiny_future::from_futures_oneshot(receiver);
+
+    // Dropping the getter must fire the cancellation future of the sender
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        drop(getter);
+    });
+    executor::block_on(sender.cancellation());
+    assert!(sender.is_canceled(), "Channel has not been cancelled");
+}
@@ -0,0 +1,84 @@
+use std::{sync::Arc, thread};
+use tiny_future::FuturePool;
+
+#[test]
+fn pool() {
+    let pool = FuturePool::new();
+    let (setter, getter) = pool.acquire();
+    let id = getter.id();
+
+    // Complete the future and drop both halves
+    setter.set(7u8);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+    assert_eq!(pool.idle(), 1, "Future has not been returned to the pool");
+
+    // The reused future must start from a fresh pending state
+    let (setter, getter) = pool.acquire();
+    assert_eq!(pool.idle(), 0, "Future has not been reused");
+    assert_ne!(getter.id(), id, "Reused future has a stale identity");
+    assert!(!getter.is_ready(), "Reused future has a stale result");
+    setter.set(4);
+    assert_eq!(getter.wait(), Some(4), "Future has invalid result");
+}
+
+#[test]
+fn pool_stale() {
+    let pool = FuturePool::<u8>::new();
+
+    // Cancel the future and leave a result unclaimed
+    let (setter, getter) = pool.acquire();
+    drop((setter, getter));
+    let (setter, getter) = pool.acquire();
+    setter.set(7);
+    drop(getter);
+
+    // Neither the cancellation nor the unclaimed result must carry over
+    let (setter, getter) = pool.acquire();
+    assert!(!setter.is_cancelled(), "Reused future has a stale cancellation");
+    assert!(!getter.is_cancelled(), "Reused future has a stale cancellation");
+    assert!(getter.try_get().is_err(), "Reused future has a stale result");
+    drop(setter);
+}
+
+#[test]
+fn pool_weak() {
+    let pool = FuturePool::<u8>::new();
+
+    // A future that is still observable via a weak setter must not be reused
+    let (setter, getter) = pool.acquire();
+    let weak = setter.downgrade();
+    drop((setter, getter));
+    let (setter, getter) = pool.acquire();
+    assert!(weak.upgrade().is_none(), "Weak setter observes a reused future");
+    setter.set(7);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn pool_threads() {
+    let pool = Arc::new(FuturePool::<usize>::new());
+
+    // Hammer the pool from several threads, each completing its futures on another thread
+    let threads: Vec<_> = (0..8)
+        .map(|thread| {
+            let pool = pool.clone();
+            thread::spawn(move || {
+                for i in 0..2_000 {
+                    let value = thread * 1_000_000 + i;
+                    let (setter, getter) = pool.acquire();
+                    let worker = thread::spawn(move || setter.set(value));
+                    assert_eq!(getter.wait(), Some(value), "Future has invalid result");
+                    worker.join().expect("Worker thread panicked");
+
+                    // Drop a cancelled future as well
+                    let (setter, getter) = pool.acquire();
+                    drop((getter, setter));
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().expect("Pool thread panicked");
+    }
+    assert!(pool.idle() > 0, "No future has been returned to the pool");
+}
@@ -0,0 +1,47 @@
+#![cfg(unix)]
+
+use std::{
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn from_child() {
+    let child = Command::new("sh").args(["-c", "exit 3"]).spawn().expect("Failed to spawn child");
+    let status = tiny_future::from_child(child).wait().expect("Future has been cancelled");
+    assert_eq!(status.code(), Some(3), "Future has invalid result");
+}
+
+#[test]
+fn from_child_output() {
+    let child = Command::new("sh")
+        .args(["-c", "echo seven; echo error >&2"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn child");
+    let output = tiny_future::from_child_output(child).wait().expect("Future has been cancelled");
+    assert!(output.status.success(), "Child has failed");
+    assert_eq!(output.stdout, b"seven\n", "Future has invalid stdout");
+    assert_eq!(output.stderr, b"error\n", "Future has invalid stderr");
+}
+
+#[test]
+fn from_child_cancelled() {
+    let child = Command::new("sleep").arg("30").stdout(Stdio::piped()).spawn().expect("Failed to spawn child");
+    let pid = child.id();
+    let getter = tiny_future::from_child_output(child);
+
+    // Dropping the getter must kill and reap the child
+    let start = Instant::now();
+    drop(getter);
+    loop {
+        let status = Command::new("kill").args(["-0", &pid.to_string()]).stderr(Stdio::null()).status();
+        if !status.expect("Failed to probe child").success() {
+            break;
+        }
+        assert!(start.elapsed() < Duration::from_secs(5), "Child has not been killed");
+        thread::sleep(Duration::from_millis(10));
+    }
+}
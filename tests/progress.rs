@@ -0,0 +1,42 @@
+use std::{thread, time::Duration};
+use tiny_future::FutureState;
+
+#[test]
+fn progress() {
+    let (setter, progress, getter) = tiny_future::with_progress::<u8, u32>();
+    assert_eq!(getter.progress::<u32>(), None, "Future has unexpected progress");
+
+    // Report progress from another thread
+    let producer = thread::spawn(move || {
+        for percent in [25, 50, 100] {
+            thread::sleep(Duration::from_millis(20));
+            progress.report(percent);
+        }
+        progress
+    });
+
+    // Follow the progress until it is complete
+    let mut latest = 0u32;
+    while latest < 100 {
+        latest = getter.wait_progress_timeout(Duration::from_secs(2)).unwrap_or(latest);
+    }
+    assert_eq!(getter.progress::<u32>(), Some(100), "Future has invalid progress");
+
+    // Progress reports after the completion must be ignored
+    let progress = producer.join().expect("Producer thread panicked");
+    setter.set(7);
+    progress.report(0);
+    assert_eq!(getter.progress::<u32>(), Some(100), "Future has invalid progress");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn progress_timeout() {
+    let (setter, _progress, getter) = tiny_future::with_progress::<u8, u32>();
+    assert_eq!(getter.wait_progress_timeout::<u32>(Duration::from_millis(10)), None, "Future has unexpected progress");
+
+    // A resolution must end the wait without consuming the result
+    setter.set(7);
+    assert_eq!(getter.wait_progress_timeout::<u32>(Duration::from_secs(2)), None, "Future has unexpected progress");
+    assert_eq!(getter.state(), FutureState::Ready, "Future has invalid state");
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+use tiny_future::{Cancelled, Getter, Setter};
+
+#[test]
+fn setter_round_trip() {
+    let (setter, getter) = tiny_future::new::<String>();
+
+    // Smuggle the setter through a raw pointer and drop the reconstituted setter
+    let raw = setter.into_raw();
+    assert!(!getter.is_cancelled(), "Raw setter has cancelled the future");
+    let setter = unsafe { Setter::<String>::from_raw(raw) };
+    drop(setter);
+    assert_eq!(getter.wait_result(), Err(Cancelled::SetterDropped), "Reconstituted setter has not cancelled on drop");
+}
+
+#[test]
+fn getter_round_trip() {
+    let (setter, getter) = tiny_future::new::<String>();
+
+    // Smuggle the getter through a raw pointer
+    let raw = getter.into_raw();
+    setter.set("seven".to_string());
+    let getter = unsafe { Getter::<String>::from_raw(raw) };
+    assert_eq!(getter.wait().as_deref(), Some("seven"), "Future has invalid result");
+}
+
+#[test]
+fn leaked_raw_setter() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // A raw setter that is never reclaimed must count as live without cancelling the future
+    let _raw = setter.into_raw();
+    assert!(getter.has_setter(), "Raw setter is not detectable as live");
+    let getter = getter.wait_timeout(Duration::from_millis(50)).expect_err("Future has been resolved");
+    assert!(!getter.is_cancelled(), "Future has been cancelled");
+}
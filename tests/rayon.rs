@@ -0,0 +1,60 @@
+#![cfg(feature = "rayon")]
+
+use rayon::ThreadPoolBuilder;
+use std::{
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+};
+
+#[test]
+fn spawn() {
+    assert_eq!(tiny_future::rayon::spawn(|| 7).wait(), Some(7), "Future has invalid result");
+    assert_eq!(tiny_future::rayon::spawn_fifo(|| 4).wait(), Some(4), "Future has invalid result");
+}
+
+#[test]
+fn spawn_in() {
+    let pool = ThreadPoolBuilder::new().num_threads(1).build().expect("Failed to build thread pool");
+    assert_eq!(tiny_future::rayon::spawn_in(&pool, || 7).wait(), Some(7), "Future has invalid result");
+    assert_eq!(tiny_future::rayon::spawn_fifo_in(&pool, || 4).wait(), Some(4), "Future has invalid result");
+}
+
+#[test]
+fn spawn_panic() {
+    // A panicking task must cancel its getter without tearing down the pool
+    let pool = ThreadPoolBuilder::new().num_threads(1).build().expect("Failed to build thread pool");
+    let getter = tiny_future::rayon::spawn_in(&pool, || -> u8 { panic::panic_any("Test panic") });
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert_eq!(tiny_future::rayon::spawn_in(&pool, || 7).wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn spawn_cancelled() {
+    let pool = ThreadPoolBuilder::new().num_threads(1).build().expect("Failed to build thread pool");
+
+    // Block the only worker and queue a task behind it
+    let (gate, blocker) = tiny_future::new::<()>();
+    let running = tiny_future::rayon::spawn_fifo_in(&pool, move || blocker.wait());
+    let started = Arc::new(AtomicBool::default());
+    let queued = {
+        let started = started.clone();
+        tiny_future::rayon::spawn_fifo_in(&pool, move || started.store(true, SeqCst))
+    };
+
+    // Cancel the queued task before it has been started
+    drop(queued);
+    gate.set(());
+    assert_eq!(running.wait(), Some(Some(())), "Future has invalid result");
+    assert_eq!(tiny_future::rayon::spawn_fifo_in(&pool, || 7).wait(), Some(7), "Future has invalid result");
+    assert!(!started.load(SeqCst), "Cancelled task has been started");
+}
+
+#[test]
+fn join() {
+    let (a, b) = tiny_future::rayon::join(|| 7, || "seven");
+    assert_eq!(a.wait(), Some(7), "Future has invalid result");
+    assert_eq!(b.wait(), Some("seven"), "Future has invalid result");
+}
@@ -0,0 +1,61 @@
+use std::{thread, time::Duration};
+use tiny_future::CancelReason;
+
+/// The reasons why a request may be cancelled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shutdown {
+    Disconnected,
+    DeadlineExceeded,
+}
+
+#[test]
+fn getter_reason() {
+    let (setter, getter) = tiny_future::with_reason::<u8, Shutdown>();
+
+    // Cancel the future with a reason from the getter side
+    getter.cancel_with(Shutdown::Disconnected);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(setter.cancellation_reason(), Some(&Shutdown::Disconnected), "Future has invalid reason");
+    assert_eq!(getter.wait(), Err(CancelReason::Reason(Shutdown::Disconnected)), "Future has invalid result");
+}
+
+#[test]
+fn setter_reason() {
+    let (setter, getter) = tiny_future::with_reason::<u8, Shutdown>();
+
+    // Cancel the future with a reason from the setter side after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.cancel_with(Shutdown::DeadlineExceeded);
+    });
+    assert_eq!(getter.wait(), Err(CancelReason::Reason(Shutdown::DeadlineExceeded)), "Future has invalid result");
+}
+
+#[test]
+fn dropped_reason() {
+    let (setter, getter) = tiny_future::with_reason::<u8, Shutdown>();
+
+    // Dropping the setter must cancel the future without a reason
+    drop(setter);
+    assert_eq!(getter.cancellation_reason(), None, "Future has a reason");
+    assert_eq!(getter.wait(), Err(CancelReason::Dropped), "Future has invalid result");
+}
+
+#[test]
+fn resolved_ignores_reason() {
+    let (setter, getter) = tiny_future::with_reason::<u8, Shutdown>();
+    setter.set(7);
+
+    // Cancelling a resolved future must neither cancel it nor record the reason
+    getter.cancel_with(Shutdown::Disconnected);
+    assert_eq!(getter.cancellation_reason(), None, "Resolved future has a reason");
+    assert_eq!(getter.wait(), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn first_reason_wins() {
+    let (setter, getter) = tiny_future::with_reason::<u8, Shutdown>();
+    setter.cancel_with(Shutdown::DeadlineExceeded);
+    getter.cancel_with(Shutdown::Disconnected);
+    assert_eq!(getter.wait(), Err(CancelReason::Reason(Shutdown::DeadlineExceeded)), "Future has invalid result");
+}
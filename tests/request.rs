@@ -0,0 +1,46 @@
+use std::{thread, time::Duration};
+
+#[test]
+fn request() {
+    let (requester, responder) = tiny_future::request::<u32, u32>();
+
+    // Serve the requests from another thread
+    let server = thread::spawn(move || {
+        while let Some((request, reply)) = responder.recv() {
+            reply.set(request * 2);
+        }
+    });
+
+    // Every request must get exactly its own reply
+    let replies: Vec<_> = (0..8).map(|i| requester.send(i)).collect();
+    for (i, reply) in replies.into_iter().enumerate() {
+        assert_eq!(reply.wait(), Some(i as u32 * 2), "Future has invalid result");
+    }
+
+    // Dropping all requesters must stop the server
+    drop(requester);
+    server.join().expect("Server thread panicked");
+}
+
+#[test]
+fn request_cancelled_reply() {
+    let (requester, responder) = tiny_future::request::<u8, u8>();
+
+    // Cancelling the reply getter must be visible to the responder
+    drop(requester.send(7));
+    let (request, reply) = responder.recv_timeout(Duration::from_secs(1)).expect("Request is missing");
+    assert_eq!(request, 7, "Request has invalid value");
+    assert!(reply.is_cancelled(), "Reply has not been cancelled");
+    assert!(responder.try_recv().is_none(), "Responder has received an unexpected request");
+}
+
+#[test]
+fn request_responder_dropped() {
+    let (requester, responder) = tiny_future::request::<u8, u8>();
+
+    // Dropping the responder must cancel outstanding and future replies
+    let outstanding = requester.send(1);
+    drop(responder);
+    assert_eq!(outstanding.wait(), None, "Reply has not been cancelled");
+    assert_eq!(requester.send(2).wait(), None, "Reply has not been cancelled");
+}
@@ -0,0 +1,81 @@
+use std::{error::Error, fmt, thread, time::Duration};
+use tiny_future::{Cancelled, FutureError};
+
+/// A test error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Failure;
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failure")
+    }
+}
+impl Error for Failure {
+    // No members to override
+}
+
+#[test]
+fn set_ok() {
+    let (setter, getter) = tiny_future::new::<Result<u8, Failure>>();
+    setter.set_ok(7);
+    assert_eq!(getter.wait_ok(), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn set_err() {
+    let (setter, getter) = tiny_future::new::<Result<u8, Failure>>();
+    setter.set_err(Failure);
+
+    // The error must be flattened and exposed as source
+    let error = getter.wait_ok().expect_err("Future has not failed");
+    assert_eq!(error, FutureError::Failed(Failure), "Future has invalid error");
+    assert_eq!(error.to_string(), "The future has failed: failure", "Error has invalid message");
+    assert!(error.source().is_some(), "Error has no source");
+}
+
+#[test]
+fn wait_ok_cancelled() {
+    let (setter, getter) = tiny_future::new::<Result<u8, Failure>>();
+    drop(setter);
+    assert_eq!(getter.wait_ok(), Err(FutureError::Cancelled(Cancelled::SetterDropped)), "Future has invalid result");
+}
+
+#[test]
+fn wait_ok_timeout() {
+    // The wait must time out while the future is pending, and hand the getter back without cancelling the future
+    let (setter, getter) = tiny_future::new::<Result<u8, Failure>>();
+    let getter = getter.wait_ok_timeout(Duration::from_millis(50)).expect_err("Future has not timed out");
+    assert!(!setter.is_cancelled(), "Future has been cancelled on timeout");
+
+    // The result must be returned if it arrives in time
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter.set_ok(7);
+    });
+    let result = getter.wait_ok_timeout(Duration::from_secs(10)).expect("Future has timed out");
+    assert_eq!(result, Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn new_result() {
+    let (setter, getter) = tiny_future::new_result::<u8, Failure>();
+    setter.ok(7);
+    assert_eq!(getter.wait(), Ok(7), "Future has invalid result");
+
+    let (setter, getter) = tiny_future::new_result::<u8, Failure>();
+    setter.err(Failure);
+    assert_eq!(getter.wait(), Err(FutureError::Failed(Failure)), "Future has invalid error");
+}
+
+#[test]
+fn new_result_cancelled() {
+    let (setter, getter) = tiny_future::new_result::<u8, Failure>();
+    setter.cancel();
+    assert_eq!(getter.wait(), Err(FutureError::Cancelled(Cancelled::Explicit)), "Future has invalid result");
+
+    // The wait must time out while the future is pending and hand the getter back
+    let (setter, getter) = tiny_future::new_result::<u8, Failure>();
+    let getter = getter.wait_timeout(Duration::from_millis(50)).expect_err("Future has not timed out");
+    assert!(!setter.is_cancelled(), "Future has been cancelled on timeout");
+    setter.ok(7);
+    assert_eq!(getter.wait_timeout(Duration::ZERO).ok(), Some(Ok(7)), "Future has invalid result");
+}
@@ -0,0 +1,79 @@
+use std::{thread, time::Duration};
+
+#[test]
+fn select() {
+    let (setter_a, mut getter_a) = tiny_future::new::<u8>();
+    let (setter_b, mut getter_b) = tiny_future::new::<String>();
+
+    // Resolve the second future after a short delay
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        setter_b.set("seven".to_string());
+    });
+
+    let fired = tiny_future::select! {
+        _ = getter_a => { "a" },
+        message = getter_b => {
+            assert_eq!(message.as_deref(), Some("seven"), "Future has invalid result");
+            "b"
+        },
+    };
+    assert_eq!(fired, "b", "Invalid arm has fired");
+
+    // The other getter must be left untouched
+    setter_a.set(7);
+    assert_eq!(getter_a.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn select_order() {
+    let (setter_a, mut getter_a) = tiny_future::new::<u8>();
+    let (setter_b, mut getter_b) = tiny_future::new::<u8>();
+    setter_b.set(4);
+    setter_a.set(7);
+
+    // If several arms are ready, the first one in source order must fire
+    let value = tiny_future::select! {
+        value = getter_a => { value },
+        value = getter_b => { value.map(|value| value * 10) },
+        timeout(Duration::ZERO) => { None },
+    };
+    assert_eq!(value, Some(7), "Invalid arm has fired");
+    let value = tiny_future::select! {
+        value = getter_b => { value },
+        value = getter_a => { value },
+    };
+    assert_eq!(value, Some(4), "Invalid arm has fired");
+}
+
+#[test]
+fn select_cancelled() {
+    let (setter, mut getter) = tiny_future::new::<u8>();
+    drop(setter);
+    let value = tiny_future::select! {
+        value = getter => { value },
+    };
+    assert_eq!(value, None, "Future has not been cancelled");
+}
+
+#[test]
+fn select_timeout() {
+    let (_setter_a, mut getter_a) = tiny_future::new::<u8>();
+    let (_setter_b, mut getter_b) = tiny_future::new::<u8>();
+
+    // Select in a loop until the timeout arm fires
+    let mut timeouts = 0;
+    loop {
+        tiny_future::select! {
+            _ = getter_a => { unreachable!("Future is not pending") },
+            _ = getter_b => { unreachable!("Future is not pending") },
+            timeout(Duration::from_millis(20)) => {
+                timeouts += 1;
+                if timeouts == 3 {
+                    break;
+                }
+            }
+        }
+    }
+    assert!(!getter_a.is_cancelled(), "Future has been cancelled");
+}
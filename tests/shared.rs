@@ -0,0 +1,139 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+use tiny_future::Cancelled;
+
+#[test]
+fn shared() {
+    let (setter, getter) = tiny_future::new::<String>();
+    let getter = getter.shared();
+
+    // Spawn many waiters
+    let waiters: Vec<_> = (0..16)
+        .map(|_| {
+            let getter = getter.clone();
+            thread::spawn(move || getter.wait())
+        })
+        .collect();
+
+    // Set the result after a short delay
+    thread::sleep(Duration::from_millis(100));
+    setter.set("seven".to_string());
+
+    // Every waiter must receive its own clone
+    for waiter in waiters {
+        let result = waiter.join().expect("Waiter thread panicked");
+        assert_eq!(result.as_deref(), Some("seven"), "Future has invalid result");
+    }
+    assert_eq!(getter.wait().as_deref(), Some("seven"), "Future has invalid result");
+}
+
+#[test]
+fn shared_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let getter = getter.shared();
+    let waiter = {
+        let getter = getter.clone();
+        thread::spawn(move || getter.wait())
+    };
+
+    // Drop the setter
+    thread::sleep(Duration::from_millis(100));
+    drop(setter);
+    assert_eq!(waiter.join().expect("Waiter thread panicked"), None, "Future has not been cancelled");
+    assert!(getter.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn shared_drop() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let getter = getter.shared();
+    let mut clones: Vec<_> = (0..3).map(|_| getter.clone()).collect();
+
+    // Dropping some clones must not cancel the future
+    drop(getter);
+    let last = clones.pop().expect("Clone is missing");
+    drop(clones);
+    assert!(!setter.is_cancelled(), "Future has been cancelled too early");
+
+    // Dropping the last clone must cancel the future
+    drop(last);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn shared_future() {
+    let (setter, getter) = tiny_future::new::<Mutex<String>>();
+    let future = getter.shared_future();
+    assert!(future.get().is_none(), "Future is not pending");
+
+    // Spawn many readers that borrow the result
+    let readers: Vec<_> = (0..16)
+        .map(|_| {
+            let future = future.clone();
+            thread::spawn(move || future.wait().map(|result| result.lock().unwrap().clone()))
+        })
+        .collect();
+
+    // Set the result after a short delay
+    thread::sleep(Duration::from_millis(100));
+    setter.set(Mutex::new("seven".to_string()));
+    for reader in readers {
+        let result = reader.join().expect("Reader thread panicked");
+        assert_eq!(result.as_deref(), Ok("seven"), "Future has invalid result");
+    }
+
+    // The reference must outlive dropped handles
+    let result = future.get().expect("Future has no result");
+    drop(future.clone());
+    assert_eq!(result.lock().unwrap().as_str(), "seven", "Future has invalid result");
+}
+
+#[test]
+fn shared_future_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let future = getter.shared_future();
+
+    // Drop the setter
+    drop(setter);
+    assert_eq!(future.wait(), Err(Cancelled::SetterDropped), "Future has not been cancelled");
+    assert!(future.get().is_none(), "Future has invalid result");
+    assert!(future.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn shared_future_drop() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let future = getter.shared_future();
+    let clone = future.clone();
+
+    // Only dropping the last handle must cancel the future
+    drop(future);
+    assert!(!setter.is_cancelled(), "Future has been cancelled too early");
+    drop(clone);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+}
+
+#[test]
+fn shared_future_lazy() {
+    let evaluated = Arc::new(AtomicBool::new(false));
+    let future = {
+        let evaluated = evaluated.clone();
+        tiny_future::lazy(move || {
+            evaluated.store(true, SeqCst);
+            7u8
+        })
+        .shared_future()
+    };
+
+    // A non-blocking check must not evaluate the closure, but a wait must
+    assert!(future.get().is_none(), "Future is not pending");
+    assert!(!evaluated.load(SeqCst), "Closure has been evaluated by a non-blocking check");
+    assert_eq!(future.wait(), Ok(&7), "Future has invalid result");
+    assert_eq!(future.get(), Some(&7), "Future has invalid result");
+}
@@ -0,0 +1,51 @@
+use std::{thread, time::Duration};
+
+#[test]
+fn slot_ping_pong() {
+    let (ping_setter, ping_getter) = tiny_future::slot::<u32>();
+    let (pong_setter, pong_getter) = tiny_future::slot::<u32>();
+
+    // Bounce a counter between two threads
+    let peer = thread::spawn(move || {
+        while let Some(value) = ping_getter.take() {
+            pong_setter.put(value + 1).expect("Slot has been closed");
+        }
+    });
+    for i in (0..100).step_by(2) {
+        ping_setter.put(i).expect("Slot has been closed");
+        assert_eq!(pong_getter.take(), Some(i + 1), "Slot has invalid value");
+    }
+
+    // Closing the slot must stop the peer
+    drop(ping_setter);
+    peer.join().expect("Peer thread panicked");
+    assert!(pong_getter.is_closed(), "Slot has not been closed");
+}
+
+#[test]
+fn slot_displaced() {
+    let (setter, getter) = tiny_future::slot::<u8>();
+    assert_eq!(setter.put(1), Ok(None), "Slot has displaced a value");
+    assert_eq!(setter.put(2), Ok(Some(1)), "Slot has not displaced the unconsumed value");
+
+    // Every value must be delivered at most once
+    assert_eq!(getter.take(), Some(2), "Slot has invalid value");
+    assert_eq!(getter.take_timeout(Duration::from_millis(10)), None, "Slot has delivered a value twice");
+}
+
+#[test]
+fn slot_closed() {
+    let (setter, getter) = tiny_future::slot::<u8>();
+
+    // A value that has been put before closing must still be delivered
+    setter.put(7).expect("Slot has been closed");
+    setter.close();
+    assert_eq!(setter.put(8), Err(8), "Slot has not been closed");
+    assert_eq!(getter.take(), Some(7), "Slot has invalid value");
+    assert_eq!(getter.take(), None, "Slot has not been closed");
+
+    // Dropping the getter must close the slot for the setter
+    let (setter, getter) = tiny_future::slot::<u8>();
+    drop(getter);
+    assert!(setter.is_closed(), "Slot has not been closed");
+}
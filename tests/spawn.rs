@@ -0,0 +1,93 @@
+use std::{sync::mpsc, thread, time::Duration};
+use tiny_future::JoinOutcome;
+
+#[test]
+fn spawn() {
+    let getter = tiny_future::spawn(|| {
+        thread::sleep(Duration::from_millis(50));
+        7u8
+    });
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn spawn_panic() {
+    let getter = tiny_future::spawn::<u8, _>(|| panic!("worker failed"));
+    assert!(matches!(getter.wait_outcome(), JoinOutcome::Panicked(_)), "Panic has not been delivered");
+}
+
+#[test]
+fn spawn_scoped() {
+    let values: Vec<u32> = (1..=4).collect();
+    let sums = thread::scope(|scope| {
+        // Spawn several futures that borrow from the stack
+        let getters: Vec<_> =
+            values.chunks(2).map(|chunk| tiny_future::spawn_scoped(scope, move || chunk.iter().sum::<u32>())).collect();
+
+        // Join the futures before the scope ends
+        getters.into_iter().map(|getter| getter.wait().expect("Future has been cancelled")).collect::<Vec<_>>()
+    });
+    assert_eq!(sums, [3, 7], "Future has invalid result");
+}
+
+#[test]
+fn spawn_scoped_borrowed_result() {
+    let values: Vec<u32> = (1..=3).collect();
+    thread::scope(|scope| {
+        // The result itself may borrow from the stack too
+        let getter = tiny_future::spawn_scoped(scope, || values.iter().max());
+        assert_eq!(getter.wait(), Some(Some(&3)), "Future has invalid result");
+    });
+}
+
+#[test]
+fn spawn_with() {
+    let builder = thread::Builder::new().name("worker-7".to_string());
+    let getter = tiny_future::spawn_with(builder, || thread::current().name().map(str::to_string))
+        .expect("Failed to spawn worker thread");
+
+    // The getter must expose the thread name
+    assert_eq!(getter.label(), Some("worker-7"), "Future has invalid label");
+    assert!(format!("{getter:?}").contains("worker-7"), "Getter debug representation does not contain the label");
+    assert_eq!(getter.wait(), Some(Some("worker-7".to_string())), "Future has invalid result");
+}
+
+#[test]
+fn spawn_with_unnamed() {
+    let getter = tiny_future::spawn_with(thread::Builder::new(), || 7u8).expect("Failed to spawn worker thread");
+    assert_eq!(getter.label(), None, "Future has unexpected label");
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn from_join_handle() {
+    let handle = thread::spawn(|| {
+        thread::sleep(Duration::from_millis(50));
+        7u8
+    });
+    let getter = tiny_future::from_join_handle(handle);
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn from_join_handle_panic() {
+    let handle = thread::spawn(|| -> u8 { panic!("worker failed") });
+    let getter = tiny_future::from_join_handle(handle);
+    assert!(matches!(getter.wait_outcome(), JoinOutcome::Panicked(_)), "Panic has not been delivered");
+}
+
+#[test]
+fn from_join_handle_cancelled() {
+    let (release, released) = tiny_future::new::<()>();
+    let (done, finished) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        released.wait();
+        done.send(()).expect("Failed to signal completion");
+    });
+
+    // Cancelling the getter must detach from the thread without stopping it
+    let getter = tiny_future::from_join_handle(handle);
+    drop(getter);
+    release.set(());
+    finished.recv_timeout(Duration::from_secs(2)).expect("Thread has been stopped");
+}
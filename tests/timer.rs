@@ -0,0 +1,56 @@
+use std::time::{Duration, Instant};
+
+#[test]
+fn after() {
+    let start = Instant::now();
+    let fired = tiny_future::after(Duration::from_millis(50)).wait().expect("Timer has been cancelled");
+    assert!(fired.duration_since(start) >= Duration::from_millis(50), "Timer has fired too early");
+}
+
+#[test]
+fn after_many() {
+    // Many timers must share a single timer thread
+    let timers: Vec<_> = (0..1000).map(|i| tiny_future::after(Duration::from_millis(i % 50))).collect();
+    for timer in timers {
+        timer.wait().expect("Timer has been cancelled");
+    }
+}
+
+#[test]
+fn after_cancelled() {
+    let timer = tiny_future::after(Duration::from_secs(60 * 60));
+
+    // Cancelling the timer must resolve it immediately
+    let start = Instant::now();
+    timer.cancel();
+    assert_eq!(timer.wait(), None, "Timer has not been cancelled");
+    assert!(start.elapsed() < Duration::from_secs(1), "Timer has not been released promptly");
+}
+
+#[test]
+fn at() {
+    let deadline = Instant::now() + Duration::from_millis(50);
+    let fired = tiny_future::at(deadline).wait().expect("Timer has been cancelled");
+    assert!(fired >= deadline, "Timer has fired too early");
+}
+
+#[test]
+fn at_past() {
+    let deadline = Instant::now();
+    let timer = tiny_future::at(deadline);
+    assert!(timer.is_ready(), "Timer has not fired immediately");
+    assert!(timer.wait().expect("Timer has been cancelled") >= deadline, "Timer has fired too early");
+}
+
+#[test]
+fn at_far_future() {
+    // A deadline roughly a century from now must neither overflow nor fire
+    let deadline = Instant::now() + Duration::from_secs(100 * 365 * 24 * 60 * 60);
+    let timer = tiny_future::at(deadline);
+    let timer = timer.wait_timeout(Duration::from_millis(50)).expect_err("Timer has fired too early");
+
+    // Other timers must still fire while the distant timer is pending
+    tiny_future::after(Duration::from_millis(10)).wait().expect("Timer has been cancelled");
+    timer.cancel();
+    assert_eq!(timer.wait(), None, "Timer has not been cancelled");
+}
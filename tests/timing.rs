@@ -0,0 +1,41 @@
+#![cfg(feature = "timing")]
+
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+#[test]
+fn timing_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let created_at = Instant::now();
+
+    // The future is not completed yet
+    assert_eq!(setter.completed_at(), None, "Future has a completion time while pending");
+    assert_eq!(getter.elapsed(), None, "Future has an elapsed time while pending");
+
+    // Set the result after a short delay
+    thread::sleep(Duration::from_millis(100));
+    setter.set(7);
+    let completed_at = getter.completed_at().expect("Future has no completion time");
+    let elapsed = getter.elapsed().expect("Future has no elapsed time");
+    assert!(completed_at >= created_at + Duration::from_millis(100), "Future has invalid completion time");
+    assert!(elapsed >= Duration::from_millis(100), "Future has invalid elapsed time: {elapsed:?}");
+    assert!(elapsed < Duration::from_secs(1), "Future has invalid elapsed time: {elapsed:?}");
+
+    // Claiming the result must not change the timing
+    thread::sleep(Duration::from_millis(50));
+    assert_eq!(getter.completed_at(), Some(completed_at), "Completion time has changed");
+}
+
+#[test]
+fn timing_cancel() {
+    let (setter, getter) = tiny_future::new::<u8>();
+
+    // Cancel the future from the getter side
+    thread::sleep(Duration::from_millis(50));
+    getter.cancel();
+    let elapsed = setter.elapsed().expect("Future has no elapsed time");
+    assert!(elapsed >= Duration::from_millis(50), "Future has invalid elapsed time: {elapsed:?}");
+    assert_eq!(setter.completed_at(), getter.completed_at(), "Setter and getter disagree on the completion time");
+}
@@ -0,0 +1,150 @@
+use std::{thread, time::Duration};
+use tiny_future::{CancelScope, CancellationToken};
+
+#[test]
+fn token() {
+    let token = CancellationToken::new();
+    let pairs: Vec<_> = (0..16).map(|_| tiny_future::with_token::<u8>(&token)).collect();
+
+    // Wait on every future from a separate thread
+    let waiters: Vec<_> = pairs
+        .into_iter()
+        .map(|(setter, getter)| {
+            let waiter = thread::spawn(move || getter.wait());
+            (setter, waiter)
+        })
+        .collect();
+
+    // Cancelling the token must cancel every bound future and wake its waiter
+    thread::sleep(Duration::from_millis(50));
+    token.cancel();
+    for (setter, waiter) in waiters {
+        assert!(setter.is_cancelled(), "Future has not been cancelled");
+        assert_eq!(waiter.join().expect("Waiter thread panicked"), None, "Future has not been cancelled");
+    }
+    assert!(token.is_cancelled(), "Token has not been cancelled");
+}
+
+#[test]
+fn token_cancelled_before() {
+    let token = CancellationToken::new();
+    token.cancel();
+
+    // Binding a future to a cancelled token must cancel it immediately
+    let (setter, getter) = tiny_future::with_token::<u8>(&token);
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn token_resolved() {
+    let token = CancellationToken::new();
+    let (setter, getter) = tiny_future::with_token::<u8>(&token);
+
+    // Cancelling the token must not affect futures that have already completed
+    setter.set(7);
+    token.cancel();
+    assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+}
+
+#[test]
+fn token_wait_cancelled() {
+    let token = CancellationToken::new();
+    assert!(!token.wait_cancelled_timeout(Duration::from_millis(10)), "Token has been cancelled too early");
+
+    // Cancel a clone of the token from another thread
+    let clone = token.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        clone.cancel();
+    });
+    token.wait_cancelled();
+    assert!(token.wait_cancelled_timeout(Duration::ZERO), "Token has not been cancelled");
+}
+
+#[test]
+fn scope_tree() {
+    let root = CancelScope::new();
+    let (left, right) = (root.child(), root.child());
+
+    // Build a deep chain below the left child
+    let mut scopes = vec![left.clone()];
+    for _ in 0..64 {
+        let child = scopes.last().expect("Scope is missing").child();
+        scopes.push(child);
+    }
+    let (deep_setter, deep_getter) = scopes.last().expect("Scope is missing").bind::<u8>();
+    let (right_setter, right_getter) = right.bind::<u8>();
+
+    // Cancelling a child must cancel its descendants but leave its siblings alive
+    left.cancel();
+    assert!(scopes.iter().all(CancelScope::is_cancelled), "Descendant scope has not been cancelled");
+    assert!(deep_setter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(deep_getter.wait(), None, "Future has not been cancelled");
+    assert!(!right.is_cancelled() && !root.is_cancelled(), "Sibling or parent scope has been cancelled");
+
+    // Cancelling the root must cancel the remaining scopes
+    root.cancel();
+    assert!(right.is_cancelled(), "Child scope has not been cancelled");
+    assert!(right_setter.is_cancelled(), "Future has not been cancelled");
+    assert_eq!(right_getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn scope_dropped_child() {
+    let root = CancelScope::new();
+    let (setter, getter) = root.child().bind::<u8>();
+    let (deep_setter, deep_getter) = root.child().child().bind::<u8>();
+
+    // Cancelling the root must cancel the futures of dropped descendant scopes
+    root.cancel();
+    assert!(setter.is_cancelled(), "Future of a dropped scope has not been cancelled");
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+    assert!(deep_setter.is_cancelled(), "Future of a dropped scope has not been cancelled");
+    assert_eq!(deep_getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn scope_dropped_parent() {
+    let root = CancelScope::new();
+    let leaf = root.child().child();
+
+    // Cancelling the root must reach a live scope whose intermediate parent has been dropped
+    root.cancel();
+    assert!(leaf.is_cancelled(), "Scope with a dropped parent has not been cancelled");
+}
+
+#[test]
+fn scope_prune() {
+    let root = CancelScope::new();
+    for _ in 0..16 {
+        let (setter, getter) = root.child().bind::<u8>();
+        setter.set(7);
+        assert_eq!(getter.wait(), Some(7), "Future has invalid result");
+    }
+
+    // Dropped children without pending futures must be pruned, while live children must be kept
+    let child = root.child();
+    let _pending = root.child().bind::<u8>();
+    assert!(format!("{:?}", root.token()).contains("bound: Some(2)"), "Dropped scopes have not been pruned");
+    root.cancel();
+    assert!(child.is_cancelled(), "Child scope has not been cancelled");
+}
+
+#[test]
+fn scope_cancel_during_set() {
+    for _ in 0..64 {
+        let root = CancelScope::new();
+        let child = root.child();
+        let (setter, getter) = child.bind::<u8>();
+
+        // Race a set against the cancellation of the parent
+        let producer = thread::spawn(move || setter.try_set(7).is_ok());
+        root.cancel();
+        let delivered = producer.join().expect("Producer thread panicked");
+
+        // The outcome must be consistent on both sides
+        let expected = delivered.then_some(7);
+        assert_eq!(getter.wait(), expected, "Future has inconsistent result");
+    }
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "tokio")]
+
+use futures::executor;
+use std::{thread, time::Duration};
+use tokio::sync::oneshot::{self, error::TryRecvError, Receiver};
+
+#[test]
+fn into_tokio() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let receiver = getter.into_tokio();
+
+    // Set the result from a plain thread and await it
+    thread::spawn(move || setter.set(7));
+    assert_eq!(executor::block_on(receiver), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn into_tokio_from() {
+    let receiver: Receiver<u8> = tiny_future::ready(7).into();
+    assert_eq!(executor::block_on(receiver), Ok(7), "Future has invalid result");
+}
+
+#[test]
+fn into_tokio_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut receiver = getter.into_tokio();
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Empty), "Future is not pending");
+
+    // Dropping the setter must close the channel
+    drop(setter);
+    assert_eq!(receiver.try_recv(), Err(TryRecvError::Closed), "Channel has not been closed");
+}
+
+#[test]
+fn into_tokio_dropped() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let mut receiver = getter.into_tokio();
+    assert!(!setter.is_cancelled(), "Future has been cancelled too early");
+
+    // Closing or dropping the receiver must cancel the future
+    receiver.close();
+    assert!(setter.is_cancelled(), "Future has not been cancelled");
+    drop(receiver);
+}
+
+#[test]
+fn from_tokio() {
+    let (sender, receiver) = oneshot::channel::<u8>();
+    let getter = tiny_future::from_tokio(receiver);
+
+    // Send the value from a plain thread and wait for it
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        sender.send(7).expect("Failed to send value");
+    });
+    let result = getter.wait_timeout(Duration::from_secs(5)).expect("Future has timed out");
+    assert_eq!(result, Some(7), "Future has invalid result");
+}
+
+#[test]
+fn from_tokio_cancelled() {
+    let (sender, receiver) = oneshot::channel::<u8>();
+    let getter = tiny_future::from_tokio(receiver);
+
+    // Dropping the sender must cancel the future
+    drop(sender);
+    assert_eq!(getter.wait(), None, "Future has not been cancelled");
+}
+
+#[test]
+fn from_tokio_dropped() {
+    let (sender, receiver) = oneshot::channel::<u8>();
+    let getter = tiny_future::from_tokio(receiver);
+    assert!(!sender.is_closed(), "Channel has been closed too early");
+
+    // Dropping the getter must close the channel
+    drop(getter);
+    assert!(sender.is_closed(), "Channel has not been closed");
+}
@@ -0,0 +1,58 @@
+use std::{thread, time::Duration};
+use tiny_future::WaitGroup;
+
+#[test]
+fn waitgroup() {
+    let group = WaitGroup::new();
+    let guard = group.guard();
+
+    // Hand a guard to every task
+    for i in 0..8 {
+        let guard = guard.clone();
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10 * i));
+            drop(guard);
+        });
+    }
+    drop(guard);
+    assert!(group.wait_timeout(Duration::from_secs(2)), "Tasks are not done");
+    assert_eq!(group.count(), 0, "Wait group has invalid count");
+}
+
+#[test]
+fn waitgroup_add_done() {
+    let group = WaitGroup::new();
+    group.add(2);
+    assert!(!group.wait_timeout(Duration::from_millis(10)), "Tasks are done too early");
+
+    // Complete the tasks from another thread
+    let clone = group.clone();
+    thread::spawn(move || {
+        clone.done();
+        thread::sleep(Duration::from_millis(50));
+        clone.done();
+    });
+    group.wait();
+    assert_eq!(group.count(), 0, "Wait group has invalid count");
+}
+
+#[test]
+fn waitgroup_panic() {
+    let group = WaitGroup::new();
+
+    // A panicking task must still mark its guard as done exactly once
+    let guard = group.guard();
+    let task = thread::spawn(move || {
+        let _guard = guard;
+        panic!("task failed");
+    });
+    assert!(task.join().is_err(), "Task has not panicked");
+    assert_eq!(group.count(), 0, "Wait group has invalid count");
+    assert!(group.wait_timeout(Duration::ZERO), "Tasks are not done");
+}
+
+#[test]
+#[should_panic(expected = "No task is pending?!")]
+fn waitgroup_done_underflow() {
+    WaitGroup::new().done();
+}
@@ -0,0 +1,54 @@
+use std::{thread, time::Duration};
+use tiny_future::WatchOutcome;
+
+#[test]
+fn watch() {
+    let (setter, getter) = tiny_future::watch(0u32);
+    assert_eq!(getter.latest(), 0, "Watch has invalid value");
+    assert_eq!(getter.wait_changed_timeout(Duration::from_millis(10)), WatchOutcome::TimedOut, "Watch has changed");
+
+    // Publish from another thread
+    let waiter = {
+        let getter = getter.clone();
+        thread::spawn(move || (getter.wait_changed(), getter.latest()))
+    };
+    thread::sleep(Duration::from_millis(50));
+    setter.publish(7);
+    assert_eq!(waiter.join().expect("Waiter thread panicked"), (WatchOutcome::Changed, 7), "Watch has invalid value");
+}
+
+#[test]
+fn watch_latest_only() {
+    let (setter, getter) = tiny_future::watch(0u32);
+    let clone = getter.clone();
+
+    // Consumers must only see the latest value
+    for i in 1..=10 {
+        setter.publish(i);
+    }
+    assert_eq!(getter.wait_changed(), WatchOutcome::Changed, "Watch has not changed");
+    assert_eq!(getter.latest(), 10, "Watch has invalid value");
+    assert_eq!(getter.wait_changed_timeout(Duration::ZERO), WatchOutcome::TimedOut, "Watch has changed twice");
+
+    // Every clone must track its own seen version
+    assert_eq!(clone.wait_changed_timeout(Duration::ZERO), WatchOutcome::Changed, "Clone has not seen the change");
+}
+
+#[test]
+fn watch_closed() {
+    let (setter, getter) = tiny_future::watch(0u32);
+    let waiters: Vec<_> = (0..4)
+        .map(|_| {
+            let getter = getter.clone();
+            thread::spawn(move || getter.wait_changed())
+        })
+        .collect();
+
+    // Dropping the setter must wake all waiters
+    thread::sleep(Duration::from_millis(50));
+    drop(setter);
+    for waiter in waiters {
+        assert_eq!(waiter.join().expect("Waiter thread panicked"), WatchOutcome::Closed, "Watch has not been closed");
+    }
+    assert!(getter.is_closed(), "Watch has not been closed");
+}
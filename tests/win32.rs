@@ -0,0 +1,42 @@
+#![cfg(all(windows, feature = "windows"))]
+
+use std::{thread, time::Duration};
+use windows_sys::Win32::{
+    Foundation::{WAIT_OBJECT_0, WAIT_TIMEOUT},
+    System::Threading::WaitForSingleObject,
+};
+
+#[test]
+fn win32_event_set() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let event = getter.as_win32_event();
+    assert_eq!(unsafe { WaitForSingleObject(event, 10) }, WAIT_TIMEOUT, "Event is set too early");
+
+    // Set the result from another thread and wait for the event
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        setter.set(7);
+    });
+    assert_eq!(unsafe { WaitForSingleObject(event, 5000) }, WAIT_OBJECT_0, "Event has not been set");
+    assert_eq!(getter.try_get().ok(), Some(Some(7)), "Future has invalid result");
+}
+
+#[test]
+fn win32_event_cancelled() {
+    let (setter, getter) = tiny_future::new::<u8>();
+    let event = getter.as_win32_event();
+
+    // Dropping the setter must set the event
+    drop(setter);
+    assert_eq!(unsafe { WaitForSingleObject(event, 10) }, WAIT_OBJECT_0, "Event has not been set");
+}
+
+#[test]
+fn win32_event_lazy() {
+    let getter = tiny_future::ready(7u8);
+
+    // An event that is created after the future has been resolved must be set immediately
+    let event = getter.as_win32_event();
+    assert_eq!(unsafe { WaitForSingleObject(event, 10) }, WAIT_OBJECT_0, "Event has not been set");
+    assert_eq!(getter.as_win32_event(), event, "Event has been recreated");
+}